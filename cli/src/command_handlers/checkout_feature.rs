@@ -1,24 +1,39 @@
-use helpers::{Printer, ProjectConfig};
+use helpers::{t, AppError, Printer, ProjectConfig};
+use managers::tracker::{Feature, FeatureKey};
 use crate::structs::{AppState};
+use serde_json::json;
 
-pub fn checkout_feature_command(app_state: &AppState, project_config: &ProjectConfig, feature: u16) -> Result<(), String> {
+pub fn checkout_feature_command(app_state: &AppState, project_config: &ProjectConfig, feature: &FeatureKey) -> Result<(), AppError> {
     if app_state.git_manager.is_dirty()? {
-        return Err("Репозиторий содержит несохраненные файлы!".to_string());
+        return Err(AppError::dirty_repository(t!("repository-dirty")));
     };
 
-    Printer::print_info("Получаю информацию о фиче".to_string(), None);
+    let (epic, branch) = switch_to_feature(app_state, project_config, feature)?;
 
-    let epic = app_state.gitlab_manager.get_parent_epic(feature, project_config.group_id)?;
+    Printer::print_result(&json!({ "feature": epic, "branch": branch, "dry_run": app_state.dry_run }));
 
-    Printer::print_info(format!("Эпик: {} ({})", epic.title, epic.web_url), None);
+    Ok(())
+}
+
+/// Переключается на ветку фичи, для техдолга на master. Возвращает корневой эпик и ветку,
+/// на которой окажется репозиторий: с --dry-run текущая ветка не меняется
+pub fn switch_to_feature(app_state: &AppState, project_config: &ProjectConfig, feature: &FeatureKey) -> Result<(Feature, String), AppError> {
+    Printer::print_info(t!("checkout-feature-fetching"), None);
+
+    let tracker = app_state.get_feature_tracker(feature, project_config)?;
+    let epic = tracker.get_root_feature(feature)?;
 
-    if epic.is_techdebt() {
-        Printer::print_info("Эпик техдолговый. Переключаю на ветку master".to_string(), None);
+    Printer::print_info(t!("checkout-feature-epic", title = epic.title, url = epic.web_url), None);
+
+    let branch = if epic.is_techdebt() {
+        Printer::print_info(t!("checkout-feature-tech-debt"), None);
         app_state.git_manager.checkout("master".to_string(), None)?;
+        "master".to_string()
     } else {
-        Printer::print_info(format!("Переключаюсь на ветку {}", epic.get_branch_name()), None);
+        Printer::print_info(t!("switching-to-branch", branch = epic.get_branch_name()), None);
         app_state.git_manager.checkout(epic.get_branch_name(), Some("master".to_string()))?;
-    }
+        epic.get_branch_name()
+    };
 
-    Ok(())
-}
\ No newline at end of file
+    Ok((epic, branch))
+}
@@ -1,44 +1,53 @@
-use crate::command_handlers::checkout_feature_command;
-use helpers::{Printer, ProjectConfig};
+use crate::command_handlers::checkout_feature::switch_to_feature;
+use crate::command_handlers::stack::set_branch_parent;
+use crate::command_handlers::workflow::{apply_workflow_step, WorkflowStage};
+use helpers::{t, AppError, Printer, ProjectConfig};
+use managers::tracker::TaskKey;
 use crate::structs::AppState;
+use serde_json::json;
 
-pub fn checkout_task_command(app_state: &AppState, project_config: &ProjectConfig, task_id: u64) -> Result<(), String> {
+pub fn checkout_task_command(app_state: &AppState, project_config: &ProjectConfig, task: &TaskKey) -> Result<(), AppError> {
     if app_state.git_manager.is_dirty()? {
-        return Err("Репозиторий содержит несохраненные файлы!".to_string());
+        return Err(AppError::dirty_repository(t!("repository-dirty")));
     };
 
-    let issue_branch = format!("{}-task", task_id);
+    let issue_branch = task.get_branch_name();
 
-    match app_state.git_manager.get_existed_branch(issue_branch.to_string()) {
-        Ok(branch) => {
-            Printer::print_info(format!("Ветка {} уже существует, переключаюсь", branch), None);
-            app_state.git_manager.raw_checkout(branch, false)?;
-            return Ok(());
-        }
-        Err(_) => {}
+    if let Ok(branch) = app_state.git_manager.get_existed_branch(issue_branch.to_string()) {
+        Printer::print_info(t!("checkout-task-branch-exists", branch = branch), None);
+        app_state.git_manager.raw_checkout(branch.to_string(), false)?;
+        Printer::print_result(&json!({ "task": task, "branch": branch, "created": false, "dry_run": app_state.dry_run }));
+        return Ok(());
     }
 
-    Printer::print_info("Получаю информацию о задаче!".to_string(), None);
+    Printer::print_info(t!("checkout-task-fetching"), None);
 
-    let issue = app_state.gitlab_manager.get_issue(task_id, project_config.project_id)?;
+    let tracker = app_state.get_task_tracker(task, project_config)?;
+    let issue = tracker.get_task(task)?;
 
-    Printer::print_info(format!("Задача: {} ({})", issue.title, issue.web_url), None);
+    Printer::print_info(t!("checkout-task-issue", title = issue.title, url = issue.web_url), None);
 
-    match issue.epic {
+    let parent = match &issue.feature {
         None => {
-            Printer::print_warning("У задачи не указан эпик. Начинаю от мастера".to_string(), None);
-            app_state.git_manager.checkout(issue_branch, Some("master".to_string()))?;
+            Printer::print_warning(t!("checkout-task-no-epic"), None);
+            app_state.git_manager.checkout(issue_branch.to_string(), Some("master".to_string()))?;
+            set_branch_parent(app_state, issue_branch.as_str(), "master")?;
+            "master".to_string()
         }
         Some(epic) => {
-            checkout_feature_command(
-                &app_state,
-                &project_config,
-                epic.iid,
-            )?;
+            let (_, parent) = switch_to_feature(app_state, project_config, epic)?;
 
-            app_state.git_manager.raw_checkout(issue_branch, true)?;
+            app_state.git_manager.raw_checkout(issue_branch.to_string(), true)?;
+            set_branch_parent(app_state, issue_branch.as_str(), parent.as_str())?;
+            parent
         }
+    };
+
+    if let Err(err) = apply_workflow_step(app_state, project_config, task, WorkflowStage::InProgress) {
+        Printer::print_warning(t!("workflow-update-failed", error = err), None);
     }
 
+    Printer::print_result(&json!({ "task": issue, "branch": issue_branch, "parent": parent, "created": true, "dry_run": app_state.dry_run }));
+
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,194 @@
+use std::collections::BTreeMap;
+use std::io::IsTerminal;
+use dialoguer::Confirm;
+use serde::Serialize;
+use helpers::{t, AppError, LogError, Printer, ProjectConfig};
+use managers::gitlab::structs::{GlMergeRequestState, GlMergeRequestsFilter};
+use managers::tracker::{FeatureKey, TaskKey};
+use crate::structs::AppState;
+
+/// Ветки, которые никогда не удаляются, помимо защищенных в GitLab
+const KEPT_BRANCHES: [&str; 2] = ["master", "stage"];
+
+#[derive(Serialize, Default)]
+struct CleanupCandidate {
+    local: bool,
+    remote: bool,
+    reason: String,
+    deleted: bool,
+}
+
+impl CleanupCandidate {
+    fn get_location(&self) -> &str {
+        match (self.local, self.remote) {
+            (true, true) => "local, origin",
+            (true, false) => "local",
+            _ => "origin",
+        }
+    }
+}
+
+pub fn cleanup_command(app_state: &AppState, project_config: &ProjectConfig, local_only: bool, yes: bool, dry_run: bool) -> Result<(), AppError> {
+    if let Err(err) = app_state.git_manager.prune() {
+        Printer::print_warning(t!("cleanup-prune-failed", error = err), None);
+    }
+
+    // Без списка защищенных веток удалять ничего нельзя
+    let protected = app_state.gitlab_manager.get_protected_branches(project_config.project_id)
+        .map_err(|err| AppError::new(err.code, t!("cleanup-protected-failed", error = err)))?;
+    let current = app_state.git_manager.get_current_branch()?;
+
+    let is_kept = |branch: &str| {
+        KEPT_BRANCHES.contains(&branch)
+            || branch == project_config.sync.base_branch
+            || branch == current
+            || protected.iter().any(|p| p.matches(branch))
+    };
+
+    let mut candidates: BTreeMap<String, CleanupCandidate> = BTreeMap::new();
+
+    for branch in app_state.git_manager.get_branches(false)? {
+        if is_task_or_feature(branch.as_str()) && !is_kept(branch.as_str()) {
+            candidates.entry(branch).or_default().local = true;
+        }
+    }
+
+    if !local_only {
+        for branch in app_state.git_manager.get_branches(true)? {
+            if is_task_or_feature(branch.as_str()) && !is_kept(branch.as_str()) {
+                candidates.entry(branch).or_default().remote = true;
+            }
+        }
+    }
+
+    Printer::print_info(t!("cleanup-checking", count = candidates.len()), None);
+
+    candidates.retain(|branch, candidate| {
+        match get_cleanup_reason(app_state, project_config, branch) {
+            Ok(Some(reason)) => {
+                candidate.reason = reason;
+                true
+            }
+            Ok(None) => false,
+            Err(err) => {
+                Printer::print_warning(t!("cleanup-skipping", branch = branch, error = err), None);
+                false
+            }
+        }
+    });
+
+    if candidates.is_empty() {
+        Printer::print_success(t!("cleanup-nothing"), None);
+        return Ok(());
+    }
+
+    print_table(&candidates);
+
+    if dry_run {
+        Printer::print_result(&candidates);
+        return Ok(());
+    }
+
+    if !yes {
+        if !std::io::stdin().is_terminal() || Printer::is_structured() {
+            Printer::print_warning(t!("cleanup-use-yes"), None);
+            return Ok(());
+        }
+
+        let confirmed = Confirm::new()
+            .with_prompt(t!("cleanup-confirm", count = candidates.len()))
+            .default(false)
+            .interact()
+            .log_error()?;
+
+        if !confirmed {
+            return Ok(());
+        }
+    }
+
+    for (branch, candidate) in candidates.iter_mut() {
+        let mut deleted = true;
+
+        if candidate.local {
+            match app_state.git_manager.delete_branch(branch, false) {
+                Ok(_) => Printer::print_success(t!("cleanup-deleted-local", branch = branch), None),
+                Err(err) => {
+                    deleted = false;
+                    Printer::print_warning(t!("cleanup-delete-local-failed", branch = branch, error = err), None);
+                }
+            }
+        }
+
+        if candidate.remote {
+            match app_state.git_manager.delete_branch(branch, true) {
+                Ok(_) => Printer::print_success(t!("cleanup-deleted-remote", branch = branch), None),
+                Err(err) => {
+                    deleted = false;
+                    Printer::print_warning(t!("cleanup-delete-remote-failed", branch = branch, error = err), None);
+                }
+            }
+        }
+
+        candidate.deleted = deleted;
+    }
+
+    Printer::print_result(&candidates);
+
+    Ok(())
+}
+
+fn is_task_or_feature(branch: &str) -> bool {
+    TaskKey::from_branch(branch).is_some()
+        || branch.strip_prefix("feature/").is_some_and(|key| FeatureKey::parse(key).is_ok())
+}
+
+/// Причина удаления: MR ветки задачи смержен или задача (эпик) закрыта. Ветки с открытыми MR не трогаем.
+/// Ветка фичи живет, пока открыт эпик, даже если из нее уже мержили MR
+fn get_cleanup_reason(app_state: &AppState, project_config: &ProjectConfig, branch: &str) -> Result<Option<String>, AppError> {
+    let feature = branch.strip_prefix("feature/").map(FeatureKey::parse);
+
+    let filter = GlMergeRequestsFilter {
+        source_branch: Some(branch.to_string()),
+        state: Some(GlMergeRequestState::All),
+        ..Default::default()
+    };
+
+    let mrs = app_state.gitlab_manager.get_mrs(project_config.get_mr_project_id(), &filter)?;
+
+    if mrs.iter().any(|mr| mr.state == "opened") {
+        return Ok(None);
+    }
+
+    if feature.is_none()
+        && let Some(mr) = mrs.iter().find(|mr| mr.state == "merged") {
+        return Ok(Some(t!("mr-merged", iid = mr.iid)));
+    }
+
+    if let Some(TaskKey::Gitlab(iid)) = TaskKey::from_branch(branch) {
+        let issue = app_state.gitlab_manager.get_issue(iid, project_config.project_id)?;
+        if issue.state == "closed" {
+            return Ok(Some(t!("cleanup-reason-issue-closed", iid = iid)));
+        }
+    }
+
+    if let Some(Ok(FeatureKey::Gitlab(iid))) = feature {
+        let epic = app_state.gitlab_manager.get_epic(iid, project_config.group_id)?;
+        if epic.state == "closed" {
+            return Ok(Some(t!("cleanup-reason-epic-closed", iid = iid)));
+        }
+    }
+
+    Ok(None)
+}
+
+fn print_table(candidates: &BTreeMap<String, CleanupCandidate>) {
+    let (branch_header, location_header) = (t!("cleanup-header-branch"), t!("cleanup-header-location"));
+    let branch_width = candidates.keys().map(|b| b.chars().count()).max().unwrap_or(0).max(branch_header.chars().count());
+    let location_width = "local, origin".len().max(location_header.chars().count());
+
+    Printer::print(format!("{:branch_width$}  {:location_width$}  {}", branch_header, location_header, t!("cleanup-header-reason")), None);
+
+    for (branch, candidate) in candidates {
+        Printer::print(format!("{:branch_width$}  {:location_width$}  {}", branch, candidate.get_location(), candidate.reason), None);
+    }
+}
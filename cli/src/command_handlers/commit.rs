@@ -0,0 +1,216 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::LazyLock;
+use regex::Regex;
+use helpers::{t, AppError, ErrorCode, LogError, Printer, ProjectConfig, ReferencePosition};
+use managers::tracker::TaskKey;
+use crate::structs::AppState;
+use serde_json::json;
+
+/// Тип Conventional Commits в начале первой строки: `feat: `, `fix(api)!: `
+static CONVENTIONAL_PREFIX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[a-z]+(\([^)]*\))?!?: ").unwrap());
+
+pub fn commit_command(app_state: &AppState, project_config: &ProjectConfig, message: &Option<String>, commit_type: &Option<String>, all: bool) -> Result<(), AppError> {
+    let branch = app_state.git_manager.get_current_branch()?;
+    let task = TaskKey::from_branch(branch.as_str()).ok_or_else(|| AppError::new(ErrorCode::InvalidTaskKey, t!("task-from-branch-failed", branch = branch)))?;
+
+    let commit_type = match commit_type {
+        Some(t) => Some(t.to_string()),
+        None => get_commit_type(app_state, project_config, &task),
+    };
+
+    let full_message = format_commit_message(
+        message.as_deref().unwrap_or_default(),
+        &task,
+        commit_type.as_deref(),
+        project_config.commit.reference,
+    );
+
+    // Без -m сообщение дописывается в редакторе
+    app_state.git_manager.commit(full_message.as_str(), all, message.is_none())?;
+
+    Printer::print_result(&json!({ "task": task, "message": full_message }));
+
+    Ok(())
+}
+
+/// Хук prepare-commit-msg. Ошибки не должны мешать коммиту, поэтому только предупреждения
+pub fn prepare_commit_msg_hook(app_state: &AppState, project_config: &ProjectConfig, file: &PathBuf, source: &Option<String>) -> Result<(), AppError> {
+    // Сообщения мержа, squash и amend не трогаем
+    if matches!(source.as_deref(), Some("merge") | Some("squash") | Some("commit")) {
+        return Ok(());
+    }
+
+    let task = match app_state.git_manager.get_current_branch().ok().and_then(|b| TaskKey::from_branch(b.as_str())) {
+        Some(task) => task,
+        None => return Ok(()),
+    };
+
+    let content = match fs::read_to_string(file) {
+        Ok(c) => c,
+        Err(err) => {
+            Printer::print_warning(t!("commit-message-read-failed", error = err), None);
+            return Ok(());
+        }
+    };
+
+    if get_subject(content.as_str()).is_some_and(|s| s.contains(task.get_commit_reference().as_str())) {
+        return Ok(());
+    }
+
+    let commit_type = get_commit_type(app_state, project_config, &task);
+    let message = format_commit_message(content.as_str(), &task, commit_type.as_deref(), project_config.commit.reference);
+
+    if let Err(err) = fs::write(file, message) {
+        Printer::print_warning(t!("commit-message-write-failed", error = err), None);
+    }
+
+    Ok(())
+}
+
+/// Хук commit-msg: проверка первой строки по шаблону из конфига проекта
+pub fn commit_msg_hook(project_config: &ProjectConfig, file: &PathBuf) -> Result<(), AppError> {
+    let pattern = match &project_config.commit.pattern {
+        Some(p) => p,
+        None => return Ok(()),
+    };
+
+    let regex = Regex::new(pattern).map_err(|err| AppError::new(ErrorCode::Config, t!("commit-pattern-invalid", pattern = pattern, error = err)))?;
+    let content = fs::read_to_string(file).log_error()?;
+    let subject = get_subject(content.as_str()).unwrap_or_default();
+
+    if !regex.is_match(subject) {
+        return Err(AppError::new(ErrorCode::InvalidArguments, t!("commit-pattern-mismatch", subject = subject, pattern = pattern)));
+    }
+
+    Ok(())
+}
+
+/// Тип Conventional Commits по меткам задачи
+fn get_commit_type(app_state: &AppState, project_config: &ProjectConfig, task: &TaskKey) -> Option<String> {
+    let tracker = app_state.get_task_tracker(task, project_config).ok()?;
+
+    match tracker.get_task(task) {
+        Ok(issue) => issue.labels.iter().find_map(|l| project_config.commit.types.get(l).cloned()),
+        Err(err) => {
+            Printer::print_warning(t!("task-fetch-failed", task = task, error = err), None);
+            None
+        }
+    }
+}
+
+/// Добавляет в первую строку ссылку на задачу и тип, если их там еще нет. Комментарии git сохраняются.
+/// Строка не должна начинаться с `#`, иначе git считает ее комментарием: без типа ссылка GitLab берется в скобки
+fn format_commit_message(content: &str, task: &TaskKey, commit_type: Option<&str>, position: ReferencePosition) -> String {
+    let reference = task.get_commit_reference();
+
+    let mut lines: Vec<String> = content.lines().map(|l| l.to_string()).collect();
+    let subject_index = lines.iter().position(|l| !l.starts_with('#') && !l.trim().is_empty());
+
+    let subject = subject_index.map(|i| lines[i].to_string()).unwrap_or_default();
+    if subject.contains(reference.as_str()) {
+        return content.to_string();
+    }
+
+    let (prefix, text) = match CONVENTIONAL_PREFIX.find(subject.as_str()) {
+        Some(m) => (m.as_str().to_string(), subject[m.end()..].to_string()),
+        None => (commit_type.map(|t| format!("{}: ", t)).unwrap_or_default(), subject.to_string()),
+    };
+
+    let new_subject = match position {
+        ReferencePosition::Suffix if !text.is_empty() => format!("{}{} ({})", prefix, text, reference),
+        _ if prefix.is_empty() && reference.starts_with('#') => format!("[{}] {}", reference, text),
+        _ => format!("{}{} {}", prefix, reference, text),
+    };
+
+    match subject_index {
+        Some(i) => lines[i] = new_subject,
+        None => lines.insert(0, new_subject),
+    }
+
+    lines.join("\n") + "\n"
+}
+
+fn get_subject(content: &str) -> Option<&str> {
+    content.lines().find(|l| !l.starts_with('#') && !l.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gitlab_task() -> TaskKey {
+        TaskKey::Gitlab(helpers::IssueIid(12))
+    }
+
+    #[test]
+    fn prefix_without_type_does_not_start_with_comment_char() {
+        let message = format_commit_message("Fix login", &gitlab_task(), None, ReferencePosition::Prefix);
+
+        assert_eq!(message, "[#12] Fix login\n");
+    }
+
+    #[test]
+    fn prefix_with_type() {
+        let message = format_commit_message("Fix login", &gitlab_task(), Some("fix"), ReferencePosition::Prefix);
+
+        assert_eq!(message, "fix: #12 Fix login\n");
+    }
+
+    #[test]
+    fn suffix_with_type() {
+        let message = format_commit_message("Fix login", &gitlab_task(), Some("fix"), ReferencePosition::Suffix);
+
+        assert_eq!(message, "fix: Fix login (#12)\n");
+    }
+
+    #[test]
+    fn existing_conventional_type_is_kept() {
+        let message = format_commit_message("feat(auth)!: New login", &gitlab_task(), Some("fix"), ReferencePosition::Prefix);
+
+        assert_eq!(message, "feat(auth)!: #12 New login\n");
+    }
+
+    #[test]
+    fn empty_message_for_editor() {
+        for position in [ReferencePosition::Prefix, ReferencePosition::Suffix] {
+            let message = format_commit_message("", &gitlab_task(), None, position);
+
+            assert_eq!(message, "[#12] \n");
+            assert!(get_subject(message.as_str()).is_some());
+        }
+
+        let message = format_commit_message("", &gitlab_task(), Some("feat"), ReferencePosition::Suffix);
+        assert_eq!(message, "feat: #12 \n");
+    }
+
+    #[test]
+    fn git_comments_are_kept_and_skipped() {
+        let content = "\n# Please enter the commit message\n# On branch 12-task\n";
+        let message = format_commit_message(content, &gitlab_task(), None, ReferencePosition::Prefix);
+
+        assert_eq!(message, "[#12] \n\n# Please enter the commit message\n# On branch 12-task\n");
+
+        let content = "Fix login\n\n# Please enter the commit message\n";
+        let message = format_commit_message(content, &gitlab_task(), None, ReferencePosition::Suffix);
+
+        assert_eq!(message, "Fix login (#12)\n\n# Please enter the commit message\n");
+    }
+
+    #[test]
+    fn reference_is_not_added_twice() {
+        let first = format_commit_message("Fix login", &gitlab_task(), None, ReferencePosition::Prefix);
+        let second = format_commit_message(first.as_str(), &gitlab_task(), None, ReferencePosition::Prefix);
+
+        assert_eq!(first, second);
+        assert!(get_subject(first.as_str()).is_some_and(|s| s.contains("#12")));
+    }
+
+    #[test]
+    fn jira_reference() {
+        let task = TaskKey::Jira("PROJ-7".to_string());
+        let message = format_commit_message("Fix login", &task, None, ReferencePosition::Prefix);
+
+        assert_eq!(message, "PROJ-7 Fix login\n");
+    }
+}
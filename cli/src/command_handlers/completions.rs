@@ -0,0 +1,91 @@
+use clap_complete::env::Shells;
+use clap_complete::{CompletionCandidate, Shell};
+use helpers::{t, load_completion_cache, load_project_config, save_completion_cache, AppError, CompletionCache, CompletionItem, ErrorCode, LogError, Printer, ProjectConfig};
+use managers::GitManager;
+use managers::gitlab::structs::{GlIssue, GlIssueState, GlIssuesFilter};
+use crate::structs::AppState;
+use serde_json::json;
+
+/// Переменная окружения, через которую оболочка вызывает gw для дополнения
+const COMPLETE_VAR: &str = "COMPLETE";
+
+pub fn completions_command(shell: Shell) -> Result<(), AppError> {
+    let shells = Shells::builtins();
+    let completer = shells.completer(shell.to_string().as_str())
+        .ok_or(AppError::new(ErrorCode::Unsupported, t!("completions-shell-unsupported", shell = shell)))?;
+
+    // Скрипт вызывает тот же бинарник, из которого был получен
+    let exe = std::env::current_exe().log_error()?;
+
+    let mut script = vec![];
+    completer.write_registration(COMPLETE_VAR, "gw", "gw", exe.to_string_lossy().as_ref(), &mut script).log_error()?;
+
+    Printer::print(String::from_utf8_lossy(&script).trim_end().to_string(), None);
+
+    Ok(())
+}
+
+pub fn refresh_completions_command(app_state: &AppState, project_config: &ProjectConfig) -> Result<(), AppError> {
+    let current_user = app_state.gitlab_manager.get_current_user()?;
+
+    let filter = GlIssuesFilter {
+        assignee_id: Some(current_user.id),
+        state: Some(GlIssueState::Opened),
+        ..Default::default()
+    };
+    let issues = app_state.gitlab_manager.get_issues(project_config.project_id, &filter)?;
+
+    let cache = update_completion_cache(project_config, &issues)?;
+
+    Printer::print_success(t!("completions-refreshed", issues = cache.issues.len(), epics = cache.epics.len()), None);
+    Printer::print_result(&json!({ "issues": cache.issues.len(), "epics": cache.epics.len() }));
+
+    Ok(())
+}
+
+/// Сохраняет открытые задачи и их эпики для автодополнения cot и cof
+pub fn update_completion_cache(project_config: &ProjectConfig, issues: &[GlIssue]) -> Result<CompletionCache, AppError> {
+    let mut cache = CompletionCache::default();
+
+    for issue in issues {
+        cache.issues.push(CompletionItem { value: issue.iid.to_string(), title: issue.title.to_string() });
+
+        if let Some(epic) = &issue.epic
+            && !cache.epics.iter().any(|e| e.value == epic.iid.to_string()) {
+            cache.epics.push(CompletionItem { value: epic.iid.to_string(), title: epic.title.to_string() });
+        }
+    }
+
+    save_completion_cache(project_config.project_id, &cache)?;
+
+    Ok(cache)
+}
+
+pub fn complete_tasks() -> Vec<CompletionCandidate> {
+    get_cached_items(|cache| cache.issues)
+}
+
+pub fn complete_features() -> Vec<CompletionCandidate> {
+    get_cached_items(|cache| cache.epics)
+}
+
+pub fn complete_remote_branches() -> Vec<CompletionCandidate> {
+    let branches = std::env::current_dir().ok()
+        .and_then(|dir| GitManager::new(&dir, false).ok())
+        .and_then(|git| git.get_branches(true).ok())
+        .unwrap_or_default();
+
+    branches.into_iter().map(CompletionCandidate::new).collect()
+}
+
+/// Кэш проекта из текущего каталога. Без конфига проекта дополнять нечего
+fn get_cached_items(select: impl Fn(CompletionCache) -> Vec<CompletionItem>) -> Vec<CompletionCandidate> {
+    let project_config = match std::env::current_dir().ok().and_then(|dir| load_project_config(dir).ok()) {
+        Some(pc) => pc,
+        None => return vec![],
+    };
+
+    select(load_completion_cache(project_config.project_id)).into_iter()
+        .map(|item| CompletionCandidate::new(item.value).help(Some(item.title.into())))
+        .collect()
+}
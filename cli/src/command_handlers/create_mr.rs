@@ -1,35 +1,58 @@
-use helpers::{Printer, ProjectConfig};
+use helpers::{t, edit_text, AppError, Printer, ProjectConfig};
+use managers::gitlab::structs::GlMergeRequestOptions;
+use managers::tracker::{Task, TaskKey};
+use crate::command_handlers::mr_description::render_mr_description;
+use crate::commands::CreateMrArgs;
+use crate::command_handlers::workflow::{apply_workflow_step, WorkflowStage};
 use crate::structs::AppState;
-pub fn create_mr_command(app_state: &AppState, project_config: &ProjectConfig, source_branch: Option<String>, target_branch: Option<String>) -> Result<(), String> {
-    let source_branch = match source_branch {
+use serde_json::json;
+pub fn create_mr_command(app_state: &AppState, project_config: &ProjectConfig, args: &CreateMrArgs) -> Result<(), AppError> {
+    let source_branch = match args.source.to_owned() {
         None => app_state.git_manager.get_current_branch()?,
         Some(b) => b
     };
 
-    Printer::print_info(format!("Исходная ветка: {}", source_branch), None);
-    Printer::print_info("Определяю целевую ветку".to_string(), None);
+    Printer::print_info(t!("create-mr-source", branch = source_branch), None);
+    Printer::print_info(t!("create-mr-detecting-target"), None);
+
+    // С явной целевой веткой задача и эпик нужны только для заголовка и описания, и трекер не должен мешать созданию MR
+    let required = args.target.is_none();
+
+    let task = match TaskKey::from_branch(source_branch.as_str()) {
+        Some(key) => {
+            let task = app_state.get_task_tracker(&key, project_config).and_then(|tracker| tracker.get_task(&key));
+            lookup(task, required, |err| t!("task-fetch-failed", task = key, error = err))?
+        }
+        None => None,
+    };
+
+    let epic = match task.as_ref().and_then(|t| t.feature.as_ref()) {
+        Some(feature) => {
+            let epic = app_state.get_feature_tracker(feature, project_config).and_then(|tracker| tracker.get_root_feature(feature));
+            lookup(epic, required, |err| t!("feature-fetch-failed", feature = feature, error = err))?
+        }
+        None => None,
+    };
 
     let mr_title;
 
-    let target_branch = match target_branch {
+    let target_branch = match args.target.to_owned() {
         Some(b) => {
-            mr_title = b.to_string();
+            mr_title = match &task {
+                Some(task) => task.get_mr_title(),
+                None => b.to_string(),
+            };
             b
         }
         None => {
             let target;
-            if source_branch.ends_with("-task") {
-                let task_iid: u64 = source_branch.replace("-task", "").parse().unwrap();
+            if let Some(task) = &task {
+                let epic = match &epic {
+                    Some(epic) => epic,
+                    None => return Err(AppError::not_found(t!("create-mr-target-unknown"))),
+                };
 
-                let issue = app_state.gitlab_manager.get_issue(task_iid, project_config.project_id)?;
-
-                if issue.epic.is_none() {
-                    return Err("Не удалос определить цеевую ветку".to_string());
-                }
-
-                mr_title = format!("Resolve: {}", issue.title);
-
-                let epic = app_state.gitlab_manager.get_parent_epic(issue.epic.unwrap().iid, project_config.group_id)?;
+                mr_title = task.get_mr_title();
 
                 if epic.is_techdebt() {
                     target = "stage".to_string();
@@ -37,27 +60,121 @@ pub fn create_mr_command(app_state: &AppState, project_config: &ProjectConfig, s
                     target = epic.get_branch_name();
                 }
             } else {
-                return Err("Не удалось определить целевую ветку".to_string());
+                return Err(AppError::not_found(t!("create-mr-target-unknown")));
             }
 
             target
         }
     };
 
-    Printer::print_info(format!("Целевая ветка: {}", target_branch), None);
-    Printer::print_info("Создаю MR...".to_string(), None);
-
-    let description = if source_branch.ends_with("-task") { format!("#{}", source_branch.replace("-task", "")) } else { "".to_string() };
+    Printer::print_info(t!("create-mr-target", branch = target_branch), None);
 
-    let mr = app_state.gitlab_manager.create_mr(
-        source_branch,
-        target_branch,
-        project_config.project_id,
-        Some(mr_title),
-        Some(description),
+    let mut description = render_mr_description(
+        app_state,
+        project_config,
+        &args.template,
+        task.as_ref(),
+        epic.as_ref(),
+        source_branch.as_str(),
+        target_branch.as_str(),
     )?;
 
-    Printer::print_success(format!("Создан MR !{} ({})", mr.iid, mr.web_url), None);
+    if args.edit {
+        description = edit_text(description.as_str(), "mr-description.md")?.trim().to_string();
+    }
+
+    let options = get_mr_options(app_state, project_config, args, task.as_ref())?;
+
+    if app_state.dry_run {
+        Printer::print_plan(t!("create-mr-planned", source = source_branch, target = target_branch, title = mr_title), None);
+        Printer::print_result(&json!({
+            "source_branch": source_branch,
+            "target_branch": target_branch,
+            "title": mr_title,
+            "description": description,
+            "options": options,
+            "task": task,
+            "feature": epic,
+            "dry_run": true,
+        }));
+    } else {
+        Printer::print_info(t!("create-mr-creating"), None);
+
+        let mr = app_state.gitlab_manager.create_mr(
+            source_branch,
+            target_branch,
+            project_config.project_id,
+            Some(mr_title),
+            Some(description),
+            options,
+        )?;
+
+        Printer::print_success(t!("create-mr-created", iid = mr.iid, url = mr.web_url), None);
+        Printer::print_result(&json!({ "merge_request": mr, "task": task, "feature": epic }));
+    }
+
+    if let Some(task) = &task
+        && let Err(err) = apply_workflow_step(app_state, project_config, &task.key, WorkflowStage::Review) {
+        Printer::print_warning(t!("workflow-update-failed", error = err), None);
+    }
 
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Результат запроса к трекеру. Если он не обязателен, ошибка выводится предупреждением
+fn lookup<T>(result: Result<T, AppError>, required: bool, warning: impl FnOnce(AppError) -> String) -> Result<Option<T>, AppError> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(err) if !required => {
+            Printer::print_warning(warning(err), None);
+            Ok(None)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Параметры MR: флаги команды, иначе значения из конфига проекта
+fn get_mr_options(app_state: &AppState, project_config: &ProjectConfig, args: &CreateMrArgs, task: Option<&Task>) -> Result<GlMergeRequestOptions, AppError> {
+    let defaults = &project_config.merge_request;
+
+    let mut labels = if args.labels.is_empty() { defaults.labels.to_vec() } else { args.labels.to_vec() };
+
+    if let Some(task) = task
+        && (args.copy_labels || defaults.copy_issue_labels) {
+        for label in &task.labels {
+            if !labels.contains(label) {
+                labels.push(label.to_string());
+            }
+        }
+    }
+
+    let target_project_id = args.target_project.or(defaults.target_project_id);
+
+    let milestone_id = match args.milestone.to_owned().or(defaults.milestone.to_owned()) {
+        Some(title) => {
+            let project_id = target_project_id.unwrap_or(project_config.project_id);
+            Some(app_state.gitlab_manager.get_milestone(project_id, title)?.id)
+        }
+        None => None,
+    };
+
+    Ok(GlMergeRequestOptions {
+        draft: resolve_flag(args.draft, args.no_draft, Some(defaults.draft)).unwrap_or_default(),
+        labels,
+        milestone_id,
+        squash: resolve_flag(args.squash, args.no_squash, defaults.squash),
+        remove_source_branch: resolve_flag(args.remove_source_branch, args.keep_source_branch, defaults.remove_source_branch),
+        allow_collaboration: resolve_flag(args.allow_collaboration, false, defaults.allow_collaboration),
+        target_project_id,
+    })
+}
+
+fn resolve_flag(enabled: bool, disabled: bool, default: Option<bool>) -> Option<bool> {
+    if enabled {
+        Some(true)
+    } else if disabled {
+        Some(false)
+    } else {
+        default
+    }
+}
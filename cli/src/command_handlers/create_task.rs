@@ -0,0 +1,84 @@
+use std::io::IsTerminal;
+use helpers::{t, edit_text, AppError, EpicIid, Printer, ProjectConfig};
+use managers::gitlab::structs::GlNewIssue;
+use managers::tracker::{FeatureKey, TaskKey};
+use crate::command_handlers::checkout_feature::switch_to_feature;
+use crate::command_handlers::checkout_task_command;
+use crate::structs::AppState;
+use serde_json::json;
+
+pub struct NewTaskArgs {
+    pub title: String,
+    pub description: Option<String>,
+    pub labels: Vec<String>,
+    pub milestone: Option<String>,
+    pub weight: Option<u64>,
+    pub epic: Option<EpicIid>,
+    pub checkout: bool,
+}
+
+pub fn create_task_command(app_state: &AppState, project_config: &ProjectConfig, args: NewTaskArgs) -> Result<(), AppError> {
+    // Иначе задача создастся, а переключиться на ее ветку не получится
+    if args.checkout && app_state.git_manager.is_dirty()? {
+        return Err(AppError::dirty_repository(t!("repository-dirty")));
+    }
+
+    let description = match args.description {
+        Some(d) => d,
+        None if std::io::stdin().is_terminal() => {
+            Printer::print_info(t!("create-task-opening-editor"), None);
+            edit_text("", "task-description.md")?.trim().to_string()
+        }
+        None => "".to_string(),
+    };
+
+    let current_user = app_state.gitlab_manager.get_current_user()?;
+
+    let milestone_id = match args.milestone {
+        Some(title) => Some(app_state.gitlab_manager.get_milestone(project_config.project_id, title)?.id),
+        None => None,
+    };
+
+    let epic_id = match args.epic {
+        Some(iid) => Some(app_state.gitlab_manager.get_epic(iid, project_config.group_id)?.id),
+        None => None,
+    };
+
+    let new_issue = GlNewIssue {
+        title: args.title,
+        description,
+        labels: args.labels,
+        milestone_id,
+        weight: args.weight,
+        epic_id,
+        assignee_id: Some(current_user.id),
+    };
+
+    if app_state.plan(t!("dry-run-create-issue", title = new_issue.title)) {
+        // iid новой задачи неизвестен, поэтому ветка задачи в плане без номера
+        let parent = match (args.checkout, args.epic) {
+            (false, _) => None,
+            (true, Some(iid)) => Some(switch_to_feature(app_state, project_config, &FeatureKey::Gitlab(iid))?.1),
+            (true, None) => Some("master".to_string()),
+        };
+        if let Some(parent) = &parent {
+            app_state.plan(t!("dry-run-checkout-new-task", parent = parent));
+        }
+
+        Printer::print_result(&json!({ "title": new_issue.title, "labels": new_issue.labels, "checkout_parent": parent, "dry_run": true }));
+        return Ok(());
+    }
+
+    Printer::print_info(t!("create-task-creating"), None);
+
+    let issue = app_state.gitlab_manager.create_issue(project_config.project_id, new_issue)?;
+
+    Printer::print_success(t!("create-task-created", iid = issue.iid, url = issue.web_url), None);
+    Printer::print_result(&issue);
+
+    if args.checkout {
+        checkout_task_command(app_state, project_config, &TaskKey::Gitlab(issue.iid))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use helpers::{t, AppError, Printer, ProjectConfig};
+use crate::command_handlers::workflow::{apply_workflow_step, WorkflowStage};
+use crate::structs::AppState;
+use serde_json::json;
+
+pub fn done_command(app_state: &AppState, project_config: &ProjectConfig, task: &Option<String>) -> Result<(), AppError> {
+    let task = app_state.get_task_key(task)?;
+
+    Printer::print_info(t!("done-finishing", task = task), None);
+
+    apply_workflow_step(app_state, project_config, &task, WorkflowStage::Done)?;
+
+    Printer::print_success(t!("done-finished", task = task), None);
+    Printer::print_result(&json!({ "task": task, "done": true }));
+
+    Ok(())
+}
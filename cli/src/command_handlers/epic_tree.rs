@@ -0,0 +1,94 @@
+use helpers::{t, AppError, EpicIid, Printer, ProjectConfig};
+use managers::gitlab::structs::{GlEpicTree, GlIssue};
+use managers::tracker::{Feature, TaskKey};
+use crate::structs::AppState;
+
+pub fn epic_tree_command(app_state: &AppState, project_config: &ProjectConfig, epic_iid: EpicIid, mine: bool) -> Result<(), AppError> {
+    Printer::print_info(t!("epic-tree-fetching"), None);
+
+    let mut tree = app_state.gitlab_manager.get_epic_tree(epic_iid, project_config.group_id)?;
+
+    if mine {
+        let current_user = app_state.gitlab_manager.get_current_user()?;
+
+        if !retain_user_issues(&mut tree, current_user.id) {
+            Printer::print_warning(t!("epic-tree-no-my-issues"), None);
+            return Ok(());
+        }
+    }
+
+    if Printer::is_structured() {
+        Printer::print_result(&tree);
+        return Ok(());
+    }
+
+    Printer::print(format_epic(app_state, &tree), None);
+    print_children(app_state, project_config, &tree, "");
+
+    Ok(())
+}
+
+/// Оставляет в дереве только задачи пользователя и эпики, в которых они есть
+fn retain_user_issues(tree: &mut GlEpicTree, user_id: u64) -> bool {
+    tree.issues.retain(|issue| issue.assignees.iter().any(|a| a.id == user_id));
+    tree.epics.retain_mut(|child| retain_user_issues(child, user_id));
+
+    !tree.issues.is_empty() || !tree.epics.is_empty()
+}
+
+fn print_children(app_state: &AppState, project_config: &ProjectConfig, tree: &GlEpicTree, prefix: &str) {
+    let total = tree.epics.len() + tree.issues.len();
+    let mut index = 0;
+
+    for child in &tree.epics {
+        index += 1;
+        let (branch, indent) = if index == total { ("└── ", "    ") } else { ("├── ", "│   ") };
+
+        Printer::print(format!("{}{}{}", prefix, branch, format_epic(app_state, child)), None);
+        print_children(app_state, project_config, child, format!("{}{}", prefix, indent).as_str());
+    }
+
+    for issue in &tree.issues {
+        index += 1;
+        let branch = if index == total { "└── " } else { "├── " };
+
+        Printer::print(format!("{}{}{}", prefix, branch, format_issue(app_state, project_config, issue)), None);
+    }
+}
+
+/// Ветка фичи есть только у корневых эпиков: задачи дочерних и техдолговых эпиков идут в ветку корня или в master
+fn format_epic(app_state: &AppState, tree: &GlEpicTree) -> String {
+    let feature = Feature::from(tree.epic.clone());
+    let branch = if tree.epic.parent_iid.is_none() && !feature.is_techdebt() {
+        format_branch(app_state, feature.get_branch_name())
+    } else {
+        "".to_string()
+    };
+
+    format!("&{} {} [{}]{}", tree.epic.iid, tree.epic.title, tree.epic.state, branch)
+}
+
+/// Задачи эпика могут быть из других проектов группы, ветки ищем только для задач текущего проекта
+fn format_issue(app_state: &AppState, project_config: &ProjectConfig, issue: &GlIssue) -> String {
+    let assignees = if issue.assignees.is_empty() {
+        t!("epic-tree-unassigned")
+    } else {
+        issue.assignees.iter().map(|a| format!("@{}", a.username)).collect::<Vec<String>>().join(", ")
+    };
+
+    let branch = if issue.project_id == project_config.project_id {
+        format_branch(app_state, TaskKey::Gitlab(issue.iid).get_branch_name())
+    } else {
+        "".to_string()
+    };
+
+    format!("#{} {} [{}] {}{}", issue.iid, issue.title, issue.state, assignees, branch)
+}
+
+fn format_branch(app_state: &AppState, branch: String) -> String {
+    match app_state.git_manager.get_existed_branch(branch) {
+        Ok(existed) if existed.starts_with("origin/") => format!(" ⎇ {} (remote)", existed),
+        Ok(existed) => format!(" ⎇ {} (local)", existed),
+        Err(_) => "".to_string(),
+    }
+}
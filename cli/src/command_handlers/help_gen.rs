@@ -0,0 +1,67 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use clap::{Command, CommandFactory};
+use clap_mangen::Man;
+use clap_mangen::roff::{roman, Roff};
+use helpers::{t, AppError, LogError, Printer};
+use serde_json::json;
+use crate::commands::CLI;
+
+const MARKDOWN_FILE_NAME: &str = "commands.md";
+
+/// Man страницы (`<out>/man/gw*.1`) и markdown справка (`<out>/commands.md`) из определения CLI
+pub fn help_gen_command(out: &Path, dry_run: bool) -> Result<(), AppError> {
+    let mut cli = CLI::command().disable_help_subcommand(true);
+    cli.build();
+
+    let man_dir = out.join("man");
+    let markdown_path = out.join(MARKDOWN_FILE_NAME);
+
+    if dry_run {
+        Printer::print_plan(t!("dry-run-help-gen", man = man_dir.display(), markdown = markdown_path.display()), None);
+        Printer::print_result(&json!({ "man": man_dir, "markdown": markdown_path, "dry_run": true }));
+        return Ok(());
+    }
+
+    fs::create_dir_all(&man_dir).log_error()?;
+
+    let mut pages = vec![];
+    generate_man_pages(&cli, &man_dir, &mut pages)?;
+    Printer::print_success(t!("help-gen-man-written", count = pages.len(), path = man_dir.display()), None);
+
+    let options = clap_markdown::MarkdownOptions::new()
+        .title(t!("help-gen-markdown-title"))
+        .show_footer(false);
+    fs::write(&markdown_path, clap_markdown::help_markdown_command_custom(&cli, &options)).log_error()?;
+    Printer::print_success(t!("help-gen-markdown-written", path = markdown_path.display()), None);
+
+    Printer::print_result(&json!({ "man": pages, "markdown": markdown_path }));
+
+    Ok(())
+}
+
+fn generate_man_pages(cmd: &Command, dir: &Path, pages: &mut Vec<PathBuf>) -> Result<(), AppError> {
+    for subcommand in cmd.get_subcommands().filter(|s| !s.is_hide_set()) {
+        generate_man_pages(subcommand, dir, pages)?;
+    }
+
+    let man = Man::new(cmd.clone());
+    let mut content = vec![];
+    man.render(&mut content).log_error()?;
+
+    // clap_mangen не выводит псевдонимы, а по ним команды в основном и вызывают
+    let aliases: Vec<&str> = cmd.get_visible_aliases().collect();
+    if !aliases.is_empty() {
+        Roff::new()
+            .control("SH", [t!("help-gen-aliases-section").as_str()])
+            .text([roman(aliases.join(", "))])
+            .to_writer(&mut content)
+            .log_error()?;
+    }
+
+    let path = dir.join(man.get_filename());
+    fs::write(&path, content).log_error()?;
+    pages.push(path);
+
+    Ok(())
+}
@@ -0,0 +1,227 @@
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+use regex::Regex;
+use helpers::{t, AppError, ErrorCode, LogError, Printer, ProjectConfig};
+use managers::git::GW_INTERNAL_ENV;
+use managers::tracker::{FeatureKey, TaskKey};
+use crate::structs::AppState;
+use serde_json::json;
+
+/// Маркер хуков, установленных gw
+const HOOK_MARKER: &str = "# gw: managed hook";
+
+/// Суффикс, с которым сохраняется существующий хук пользователя. Он вызывается перед хуком gw
+const USER_HOOK_SUFFIX: &str = ".user";
+
+const MANAGED_HOOKS: [&str; 4] = ["pre-push", "prepare-commit-msg", "commit-msg", "post-checkout"];
+
+pub fn hooks_install_command(app_state: &AppState, project_config: &ProjectConfig) -> Result<(), AppError> {
+    let hooks_dir = app_state.git_manager.get_hooks_dir()?;
+    if !app_state.dry_run {
+        fs::create_dir_all(&hooks_dir).log_error()?;
+    }
+
+    let exe = std::env::current_exe().log_error()?;
+    let mut installed = vec![];
+
+    for hook in &project_config.hooks.enabled {
+        if !MANAGED_HOOKS.contains(&hook.as_str()) {
+            Printer::print_warning(t!("hooks-unsupported", hook = hook, available = MANAGED_HOOKS.join(", ")), None);
+            continue;
+        }
+
+        let path = hooks_dir.join(hook);
+        let user_path = get_user_hook_path(&path);
+
+        if app_state.plan(t!("dry-run-install-hook", hook = hook, path = path.display())) {
+            installed.push(hook);
+            continue;
+        }
+
+        if path.exists() && !is_managed(&path) {
+            if user_path.exists() {
+                Printer::print_warning(t!("hooks-skipped", hook = hook, path = user_path.display()), None);
+                continue;
+            }
+
+            fs::rename(&path, &user_path).log_error()?;
+            Printer::print_info(t!("hooks-chaining", hook = hook), None);
+        }
+
+        fs::write(&path, get_hook_script(exe.as_path(), hook)).log_error()?;
+
+        #[cfg(unix)]
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).log_error()?;
+
+        Printer::print_success(t!("hooks-installed", hook = hook), None);
+        installed.push(hook);
+    }
+
+    Printer::print_result(&json!({ "installed": installed, "dry_run": app_state.dry_run }));
+
+    Ok(())
+}
+
+pub fn hooks_uninstall_command(app_state: &AppState) -> Result<(), AppError> {
+    let hooks_dir = app_state.git_manager.get_hooks_dir()?;
+
+    for hook in MANAGED_HOOKS {
+        let path = hooks_dir.join(hook);
+        if !is_managed(&path) {
+            continue;
+        }
+
+        if app_state.plan(t!("dry-run-uninstall-hook", hook = hook, path = path.display())) {
+            continue;
+        }
+
+        fs::remove_file(&path).log_error()?;
+
+        let user_path = get_user_hook_path(&path);
+        if user_path.exists() {
+            fs::rename(&user_path, &path).log_error()?;
+            Printer::print_success(t!("hooks-removed-restored", hook = hook), None);
+        } else {
+            Printer::print_success(t!("hooks-removed", hook = hook), None);
+        }
+    }
+
+    Ok(())
+}
+
+pub fn hooks_status_command(app_state: &AppState, project_config: &ProjectConfig) -> Result<(), AppError> {
+    let hooks_dir = app_state.git_manager.get_hooks_dir()?;
+
+    Printer::print_info(t!("hooks-dir", path = hooks_dir.display()), None);
+
+    let mut statuses = vec![];
+
+    for hook in MANAGED_HOOKS {
+        let path = hooks_dir.join(hook);
+
+        let (state, mut text) = if is_managed(&path) {
+            ("installed", t!("hooks-state-installed"))
+        } else if path.exists() {
+            ("foreign", t!("hooks-state-foreign"))
+        } else {
+            ("missing", t!("hooks-state-missing"))
+        };
+        let enabled = project_config.hooks.enabled.iter().any(|h| h == hook);
+        let chained = get_user_hook_path(&path).exists();
+
+        if !enabled {
+            text = format!("{}, {}", text, t!("hooks-state-disabled"));
+        }
+        if chained {
+            text = format!("{}, {}", text, t!("hooks-state-chained"));
+        }
+
+        Printer::print(format!("{:20}{}", hook, text), None);
+        statuses.push(json!({ "hook": hook, "state": state, "enabled": enabled, "chained": chained }));
+    }
+
+    Printer::print_result(&statuses);
+
+    Ok(())
+}
+
+/// Хук pre-push: запрет push в защищенные ветки. Ссылки приходят в stdin
+pub fn pre_push_hook(project_config: &ProjectConfig, remote: &str) -> Result<(), AppError> {
+    let mut input = String::new();
+    std::io::stdin().read_to_string(&mut input).log_error()?;
+
+    for line in input.lines() {
+        // <local ref> <local sha> <remote ref> <remote sha>
+        let remote_ref = match line.split_whitespace().nth(2) {
+            Some(r) => r,
+            None => continue,
+        };
+
+        if let Some(branch) = remote_ref.strip_prefix("refs/heads/")
+            && project_config.hooks.protected_branches.iter().any(|b| b == branch) {
+            return Err(AppError::new(ErrorCode::Unsupported, t!("hooks-protected-push", remote = remote, branch = branch)));
+        }
+    }
+
+    Ok(())
+}
+
+/// Хук post-checkout: задача ветки и проверка имени. Никогда не прерывает checkout
+pub fn post_checkout_hook(app_state: &AppState, project_config: &ProjectConfig, flag: &str) -> Result<(), AppError> {
+    // 0 - checkout файлов, а не ветки
+    if flag != "1" {
+        return Ok(());
+    }
+
+    let branch = match app_state.git_manager.get_current_branch() {
+        Ok(b) if b != "HEAD" => b,
+        _ => return Ok(()),
+    };
+
+    if !is_valid_branch_name(project_config, branch.as_str()) {
+        Printer::print_warning(t!("hooks-branch-name", branch = branch), None);
+    }
+
+    // Переключения из gw (sync, stack rebase, cot) задачу уже показали или она не нужна
+    if !project_config.hooks.show_issue || std::env::var_os(GW_INTERNAL_ENV).is_some() {
+        return Ok(());
+    }
+
+    if let Some(key) = TaskKey::from_branch(branch.as_str()) {
+        let task = app_state.get_task_tracker(&key, project_config).and_then(|tracker| tracker.get_task(&key));
+
+        match task {
+            Ok(task) => Printer::print_info(t!("hooks-task", task = key, title = task.title, url = task.web_url), None),
+            Err(err) => Printer::print_warning(t!("task-fetch-failed", task = key, error = err), None),
+        }
+    }
+
+    Ok(())
+}
+
+fn is_valid_branch_name(project_config: &ProjectConfig, branch: &str) -> bool {
+    if let Some(pattern) = &project_config.hooks.branch_pattern {
+        return match Regex::new(pattern) {
+            Ok(regex) => regex.is_match(branch),
+            Err(err) => {
+                Printer::print_warning(t!("hooks-branch-pattern-invalid", pattern = pattern, error = err), None);
+                true
+            }
+        };
+    }
+
+    TaskKey::from_branch(branch).is_some()
+        || branch.strip_prefix("feature/").is_some_and(|key| FeatureKey::parse(key).is_ok())
+        || project_config.hooks.protected_branches.iter().any(|b| b == branch)
+        || branch == project_config.sync.base_branch
+        || branch == "stage"
+}
+
+fn get_hook_script(exe: &Path, hook: &str) -> String {
+    let exe = exe.display();
+
+    // pre-push получает ссылки в stdin, их нужно передать обоим хукам
+    if hook == "pre-push" {
+        return format!(
+            "#!/bin/sh\n{HOOK_MARKER}\ninput=$(cat)\nif [ -x \"$0{USER_HOOK_SUFFIX}\" ]; then\n  printf '%s\\n' \"$input\" | \"$0{USER_HOOK_SUFFIX}\" \"$@\" || exit $?\nfi\nprintf '%s\\n' \"$input\" | \"{exe}\" hook {hook} \"$@\"\n"
+        );
+    }
+
+    format!(
+        "#!/bin/sh\n{HOOK_MARKER}\nif [ -x \"$0{USER_HOOK_SUFFIX}\" ]; then\n  \"$0{USER_HOOK_SUFFIX}\" \"$@\" || exit $?\nfi\nexec \"{exe}\" hook {hook} \"$@\"\n"
+    )
+}
+
+fn is_managed(path: &Path) -> bool {
+    fs::read_to_string(path).is_ok_and(|content| content.contains(HOOK_MARKER))
+}
+
+fn get_user_hook_path(path: &Path) -> PathBuf {
+    let mut user_path = path.as_os_str().to_os_string();
+    user_path.push(USER_HOOK_SUFFIX);
+
+    PathBuf::from(user_path)
+}
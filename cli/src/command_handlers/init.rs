@@ -1,9 +1,10 @@
 use log::error;
 use url::Url;
-use helpers::{Printer, ProjectConfig};
+use helpers::{t, get_project_config_file_path, load_project_config, AppError, ErrorCode, Printer, ProjectConfig};
+use serde_json::json;
 use crate::structs::AppState;
 
-pub fn init_command(app_state: &AppState) -> Result<(), String> {
+pub fn init_command(app_state: &AppState) -> Result<(), AppError> {
     // Получаем ссылку на репозиторий
     let raw_remote = app_state.git_manager.get_repo_url()?;
 
@@ -12,21 +13,21 @@ pub fn init_command(app_state: &AppState) -> Result<(), String> {
         Ok(url) => url,
         Err(err) => {
             error!("{:?}", err);
-            return Err(err.to_string());
+            return Err(AppError::new(ErrorCode::Config, err.to_string()));
         }
     };
 
     // Сравниваем хосты в ссылке репозитория и конфиге утилиты
     if remote.host_str().unwrap() != app_state.app_config.gitlab_host {
-        return Err(format!("Хост репозитория проекта отличен от {}", app_state.app_config.gitlab_host));
+        return Err(AppError::new(ErrorCode::Config, t!("init-host-mismatch", host = app_state.app_config.gitlab_host)));
     }
 
-    Printer::print("Получаю информацию о проекте...".to_string(), None);
+    Printer::print(t!("init-fetching"), None);
 
     // Получаем токены пути
     let project_tokens = match remote.path_segments() {
         None => {
-            return Err("Пустой адресс репозитория".to_string());
+            return Err(AppError::not_found(t!("init-empty-remote")));
         }
         Some(t) => { t.collect::<Vec<&str>>() }
     };
@@ -34,23 +35,43 @@ pub fn init_command(app_state: &AppState) -> Result<(), String> {
     let group = app_state.gitlab_manager.get_group(project_tokens.first().unwrap().to_string())?;
     let project = app_state.gitlab_manager.get_project(project_tokens.join("/"))?;
 
-    println!("Группа: {} ({})", group.name, group.web_url);
-    println!("Проект: {} ({})", project.name, project.web_url);
+    Printer::print(t!("init-group", name = group.name, url = group.web_url), None);
+    Printer::print(t!("init-project", name = project.name, url = project.web_url), None);
 
-    let project_config = ProjectConfig {
-        project_id: project.id,
-        group_id: group.id,
+    // Сохраняем остальные настройки при повторной инициализации
+    let project_config = match load_project_config(app_state.path.to_path_buf()) {
+        Ok(existed) => ProjectConfig {
+            project_id: project.id,
+            group_id: group.id,
+            ..existed
+        },
+        Err(_) => ProjectConfig {
+            project_id: project.id,
+            group_id: group.id,
+            workflow: Default::default(),
+            mr_template: None,
+            merge_request: Default::default(),
+            sync: Default::default(),
+            commit: Default::default(),
+            hooks: Default::default(),
+        },
     };
 
-    println!("Сохраняю конфиг...");
+    if app_state.plan(t!("dry-run-save-project-config", path = get_project_config_file_path(&app_state.path).display())) {
+        Printer::print_result(&json!({ "project_config": project_config, "dry_run": true }));
+        return Ok(());
+    }
+
+    Printer::print(t!("init-saving"), None);
 
     match project_config.save(&app_state.path) {
         Ok(_) => {
-            Printer::print_success("Проект успешно инициализирован!".to_string(), None);
+            Printer::print_success(t!("init-success"), None);
+            Printer::print_result(&project_config);
             Ok(())
         }
         Err(err) => {
-            Err(format!("Ошибка при сохрании конфигурации проекта: \n {}", err))
+            Err(AppError::new(ErrorCode::Config, t!("init-save-failed", error = err)))
         }
     }
 
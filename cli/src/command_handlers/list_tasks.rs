@@ -0,0 +1,89 @@
+use std::io::IsTerminal;
+use dialoguer::FuzzySelect;
+use log::warn;
+use helpers::{t, AppError, EpicIid, LogError, Printer, ProjectConfig};
+use managers::gitlab::structs::{GlIssue, GlIssueState, GlIssuesFilter};
+use managers::tracker::TaskKey;
+use crate::command_handlers::checkout_task_command;
+use crate::command_handlers::completions::update_completion_cache;
+use crate::commands::TaskState;
+use crate::structs::AppState;
+
+pub fn list_tasks_command(app_state: &AppState, project_config: &ProjectConfig, milestone: &Option<String>, labels: &[String], state: TaskState, epic: Option<EpicIid>) -> Result<(), AppError> {
+    let current_user = app_state.gitlab_manager.get_current_user()?;
+
+    let epic_id = match epic {
+        Some(iid) => Some(app_state.gitlab_manager.get_epic(iid, project_config.group_id)?.id),
+        None => None,
+    };
+
+    let filter = GlIssuesFilter {
+        assignee_id: Some(current_user.id),
+        milestone: milestone.to_owned(),
+        labels: labels.to_vec(),
+        state: Some(match state {
+            TaskState::Opened => GlIssueState::Opened,
+            TaskState::Closed => GlIssueState::Closed,
+            TaskState::All => GlIssueState::All,
+        }),
+        epic_id,
+    };
+
+    let issues = app_state.gitlab_manager.get_issues(project_config.project_id, &filter)?;
+
+    // Полный список открытых задач заодно обновляет кэш автодополнения
+    if matches!(state, TaskState::Opened) && milestone.is_none() && labels.is_empty() && epic.is_none()
+        && let Err(err) = update_completion_cache(project_config, &issues) {
+        warn!("Не удалось обновить кэш автодополнения: {}", err);
+    }
+
+    if Printer::is_structured() {
+        Printer::print_result(&issues);
+        return Ok(());
+    }
+
+    if issues.is_empty() {
+        Printer::print_warning(t!("list-tasks-not-found"), None);
+        return Ok(());
+    }
+
+    let items: Vec<String> = issues.iter().map(format_issue).collect();
+
+    if !std::io::stdin().is_terminal() || !std::io::stderr().is_terminal() {
+        for item in items {
+            Printer::print(item, None);
+        }
+        return Ok(());
+    }
+
+    let selection = FuzzySelect::new()
+        .with_prompt(t!("list-tasks-prompt"))
+        .items(&items)
+        .default(0)
+        .interact_opt()
+        .log_error()?;
+
+    match selection {
+        Some(index) => checkout_task_command(app_state, project_config, &TaskKey::Gitlab(issues[index].iid)),
+        None => Ok(()),
+    }
+}
+
+fn format_issue(issue: &GlIssue) -> String {
+    let mut line = format!("#{} {}", issue.iid, issue.title);
+
+    if issue.state != "opened" {
+        line = format!("{} [{}]", line, issue.state);
+    }
+    if let Some(epic) = &issue.epic {
+        line = format!("{} | &{} {}", line, epic.iid, epic.title);
+    }
+    if let Some(milestone) = &issue.milestone {
+        line = format!("{} | %{}", line, milestone.title);
+    }
+    if !issue.labels.is_empty() {
+        line = format!("{} | {}", line, issue.labels.join(", "));
+    }
+
+    line
+}
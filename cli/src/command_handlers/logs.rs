@@ -0,0 +1,72 @@
+use std::fs;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::time::Duration;
+use helpers::{t, get_log_file_path, AppError, LogError, Printer};
+use serde_json::json;
+
+/// Интервал проверки новых записей при --follow
+const FOLLOW_INTERVAL: Duration = Duration::from_millis(500);
+
+pub fn logs_command(lines: usize, follow: bool, path_only: bool) -> Result<(), AppError> {
+    let path = get_log_file_path().ok_or(AppError::not_found(t!("logs-dir-not-found")))?;
+
+    if path_only {
+        Printer::print(path.display().to_string(), None);
+        Printer::print_result(&json!({ "path": path }));
+        return Ok(());
+    }
+
+    let content = fs::read(&path).unwrap_or_default();
+    if content.is_empty() && !follow {
+        Printer::print_info(t!("logs-empty", path = path.display()), None);
+        Printer::print_result(&json!({ "path": path, "lines": [] }));
+        return Ok(());
+    }
+
+    let text = String::from_utf8_lossy(&content);
+    let all_lines: Vec<&str> = text.lines().collect();
+    let tail = &all_lines[all_lines.len().saturating_sub(lines)..];
+
+    if Printer::is_structured() {
+        Printer::print_result(&json!({ "path": path, "lines": tail }));
+        return Ok(());
+    }
+
+    for line in tail {
+        Printer::print(line.to_string(), None);
+    }
+
+    if !follow {
+        return Ok(());
+    }
+
+    let mut position = content.len() as u64;
+    let mut pending = String::new();
+
+    loop {
+        std::thread::sleep(FOLLOW_INTERVAL);
+
+        let size = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        // Файл стал меньше: лог ротирован, читаем новый с начала
+        if size < position {
+            position = 0;
+        }
+        if size == position {
+            continue;
+        }
+
+        let mut file = File::open(&path).log_error()?;
+        file.seek(SeekFrom::Start(position)).log_error()?;
+        let mut chunk = vec![];
+        file.read_to_end(&mut chunk).log_error()?;
+        position += chunk.len() as u64;
+
+        pending.push_str(String::from_utf8_lossy(&chunk).as_ref());
+        // Последняя строка может быть записана не до конца
+        while let Some(index) = pending.find('\n') {
+            Printer::print(pending[..index].to_string(), None);
+            pending.drain(..=index);
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use helpers::{t, AppError, ErrorCode, MrIid, Printer, ProjectConfig};
+use managers::gitlab::structs::{GlMergeOptions, GlMergeRequest, GlMergeRequestState, GlMergeRequestsFilter};
+use crate::command_handlers::stack::retarget_children;
+use crate::commands::MrState;
+use crate::structs::AppState;
+use serde_json::json;
+
+/// MR по iid или открытый MR текущей ветки
+pub fn resolve_mr(app_state: &AppState, project_config: &ProjectConfig, iid: Option<MrIid>) -> Result<GlMergeRequest, AppError> {
+    let project_id = project_config.get_mr_project_id();
+
+    if let Some(iid) = iid {
+        return app_state.gitlab_manager.get_mr(project_id, iid);
+    }
+
+    let branch = app_state.git_manager.get_current_branch()?;
+
+    let filter = GlMergeRequestsFilter {
+        source_branch: Some(branch.to_string()),
+        state: Some(GlMergeRequestState::Opened),
+        ..Default::default()
+    };
+
+    match app_state.gitlab_manager.get_mrs(project_id, &filter)?.into_iter().next() {
+        // В списке нет части полей, поэтому запрашиваем MR целиком
+        Some(mr) => app_state.gitlab_manager.get_mr(project_id, mr.iid),
+        None => Err(AppError::not_found(t!("mr-not-found-for-branch", branch = branch))),
+    }
+}
+
+pub fn list_mrs_command(app_state: &AppState, project_config: &ProjectConfig, state: MrState, all: bool) -> Result<(), AppError> {
+    let author_id = if all { None } else { Some(app_state.gitlab_manager.get_current_user()?.id) };
+
+    let filter = GlMergeRequestsFilter {
+        author_id,
+        state: Some(match state {
+            MrState::Opened => GlMergeRequestState::Opened,
+            MrState::Closed => GlMergeRequestState::Closed,
+            MrState::Merged => GlMergeRequestState::Merged,
+            MrState::All => GlMergeRequestState::All,
+        }),
+        ..Default::default()
+    };
+
+    let mrs = app_state.gitlab_manager.get_mrs(project_config.get_mr_project_id(), &filter)?;
+
+    if Printer::is_structured() {
+        Printer::print_result(&mrs);
+        return Ok(());
+    }
+
+    if mrs.is_empty() {
+        Printer::print_warning(t!("mr-list-empty"), None);
+        return Ok(());
+    }
+
+    for mr in mrs {
+        let conflicts = if mr.has_conflicts { format!(" | {}", t!("mr-list-conflicts")) } else { String::new() };
+        Printer::print(
+            format!("!{} [{}] {} ({} → {}) @{}{}", mr.iid, mr.state, mr.title, mr.source_branch, mr.target_branch, mr.author.username, conflicts),
+            None,
+        );
+    }
+
+    Ok(())
+}
+
+pub fn view_mr_command(app_state: &AppState, project_config: &ProjectConfig, iid: Option<MrIid>) -> Result<(), AppError> {
+    let mr = resolve_mr(app_state, project_config, iid)?;
+    let approvals = app_state.gitlab_manager.get_mr_approvals(project_config.get_mr_project_id(), mr.iid)?;
+
+    if Printer::is_structured() {
+        Printer::print_result(&json!({ "merge_request": mr, "approvals": approvals }));
+        return Ok(());
+    }
+
+    let users = |users: &Vec<String>| if users.is_empty() { t!("mr-view-none") } else { users.join(", ") };
+
+    Printer::print(format!("!{} {}", mr.iid, mr.title), None);
+    Printer::print(t!("mr-view-url", url = mr.web_url), None);
+    Printer::print(format!("{}{}", t!("mr-view-state", state = mr.state), if mr.draft { " (draft)" } else { "" }), None);
+    Printer::print(t!("mr-view-branches", source = mr.source_branch, target = mr.target_branch), None);
+    Printer::print(t!("mr-view-author", author = mr.author.username), None);
+    Printer::print(t!("mr-view-assignees", users = users(&mr.assignees.iter().map(|u| format!("@{}", u.username)).collect())), None);
+    Printer::print(t!("mr-view-reviewers", users = users(&mr.reviewers.iter().map(|u| format!("@{}", u.username)).collect())), None);
+
+    match &mr.head_pipeline {
+        Some(pipeline) => Printer::print(t!("mr-view-pipeline", status = pipeline.status, url = pipeline.web_url), None),
+        None => Printer::print(t!("mr-view-no-pipeline"), None),
+    }
+
+    Printer::print(
+        format!("{}{}", t!("mr-view-merge-status", status = mr.merge_status), mr.detailed_merge_status.as_ref().map(|s| format!(" ({})", s)).unwrap_or_default()),
+        None,
+    );
+
+    if mr.has_conflicts {
+        Printer::print_warning(t!("mr-view-conflicts"), None);
+    }
+
+    let approved_by: Vec<String> = approvals.approved_by.iter().map(|a| format!("@{}", a.user.username)).collect();
+    Printer::print(t!("mr-view-approvals", users = users(&approved_by), left = approvals.approvals_left, required = approvals.approvals_required), None);
+
+    Ok(())
+}
+
+pub fn approve_mr_command(app_state: &AppState, project_config: &ProjectConfig, iid: Option<MrIid>) -> Result<(), AppError> {
+    let mr = resolve_mr(app_state, project_config, iid)?;
+
+    if app_state.plan(t!("dry-run-approve-mr", iid = mr.iid)) {
+        Printer::print_result(&json!({ "mr": mr.iid, "approved": false, "dry_run": true }));
+        return Ok(());
+    }
+
+    app_state.gitlab_manager.approve_mr(project_config.get_mr_project_id(), mr.iid)?;
+
+    Printer::print_success(t!("mr-approved", iid = mr.iid), None);
+    Printer::print_result(&json!({ "mr": mr.iid, "approved": true }));
+
+    Ok(())
+}
+
+pub fn merge_mr_command(app_state: &AppState, project_config: &ProjectConfig, iid: Option<MrIid>, when_pipeline_succeeds: bool, squash: bool, remove_source_branch: bool) -> Result<(), AppError> {
+    let mr = resolve_mr(app_state, project_config, iid)?;
+
+    if mr.has_conflicts {
+        return Err(AppError::new(ErrorCode::Conflict, t!("mr-merge-conflicts", iid = mr.iid)));
+    }
+
+    let defaults = &project_config.merge_request;
+
+    if app_state.plan(t!("dry-run-merge-mr", iid = mr.iid, source = mr.source_branch, target = mr.target_branch)) {
+        Printer::print_result(&json!({ "mr": mr.iid, "source_branch": mr.source_branch, "target_branch": mr.target_branch, "dry_run": true }));
+        return Ok(());
+    }
+
+    let merged = app_state.gitlab_manager.merge_mr(
+        project_config.get_mr_project_id(),
+        mr.iid,
+        GlMergeOptions {
+            when_pipeline_succeeds,
+            squash: if squash { Some(true) } else { defaults.squash },
+            remove_source_branch: if remove_source_branch { Some(true) } else { defaults.remove_source_branch },
+        },
+    )?;
+
+    Printer::print_result(&merged);
+
+    if merged.state == "merged" {
+        Printer::print_success(t!("mr-merged", iid = merged.iid), None);
+        retarget_children(app_state, project_config, merged.source_branch.as_str(), merged.target_branch.as_str())?;
+    } else {
+        Printer::print_success(t!("mr-merge-scheduled", iid = merged.iid), None);
+    }
+
+    Ok(())
+}
+
+pub fn rebase_mr_command(app_state: &AppState, project_config: &ProjectConfig, iid: Option<MrIid>) -> Result<(), AppError> {
+    let mr = resolve_mr(app_state, project_config, iid)?;
+
+    if app_state.plan(t!("dry-run-rebase-mr", iid = mr.iid, target = mr.target_branch)) {
+        Printer::print_result(&json!({ "mr": mr.iid, "target_branch": mr.target_branch, "dry_run": true }));
+        return Ok(());
+    }
+
+    app_state.gitlab_manager.rebase_mr(project_config.get_mr_project_id(), mr.iid)?;
+
+    Printer::print_success(t!("mr-rebase-started", iid = mr.iid, target = mr.target_branch), None);
+    Printer::print_result(&json!({ "mr": mr.iid, "rebase": "started", "target_branch": mr.target_branch }));
+
+    Ok(())
+}
+
+pub fn set_mr_state_command(app_state: &AppState, project_config: &ProjectConfig, iid: Option<MrIid>, close: bool) -> Result<(), AppError> {
+    let mr = resolve_mr(app_state, project_config, iid)?;
+
+    let plan = if close { t!("dry-run-close-mr", iid = mr.iid) } else { t!("dry-run-reopen-mr", iid = mr.iid) };
+    if app_state.plan(plan) {
+        Printer::print_result(&json!({ "mr": mr.iid, "close": close, "dry_run": true }));
+        return Ok(());
+    }
+
+    let updated = app_state.gitlab_manager.set_mr_state(project_config.get_mr_project_id(), mr.iid, close)?;
+
+    Printer::print_success(format!("MR !{}: {}", updated.iid, updated.state), None);
+    Printer::print_result(&updated);
+
+    Ok(())
+}
+
+pub fn checkout_mr_command(app_state: &AppState, project_config: &ProjectConfig, iid: MrIid) -> Result<(), AppError> {
+    if app_state.git_manager.is_dirty()? {
+        return Err(AppError::dirty_repository(t!("repository-dirty")));
+    };
+
+    let mr = app_state.gitlab_manager.get_mr(project_config.get_mr_project_id(), iid)?;
+
+    Printer::print_info(format!("MR !{}: {} ({})", mr.iid, mr.title, mr.web_url), None);
+
+    if mr.source_project_id == project_config.project_id {
+        Printer::print_info(t!("switching-to-branch", branch = mr.source_branch), None);
+
+        app_state.git_manager.fetch("origin", mr.source_branch.as_str())?;
+        app_state.git_manager.raw_checkout(mr.source_branch.to_string(), false)?;
+        Printer::print_result(&json!({ "mr": mr.iid, "branch": mr.source_branch }));
+    } else {
+        // Ветки форка доступны только через ссылку merge-requests/<iid>/head
+        let branch = format!("mr-{}-{}", mr.iid, mr.source_branch);
+        let remote = get_mr_remote(app_state, project_config)?;
+
+        Printer::print_info(t!("mr-checkout-fork", branch = branch), None);
+
+        if app_state.git_manager.get_existed_branch(branch.to_string()).is_ok() {
+            app_state.git_manager.raw_checkout(branch.to_string(), false)?;
+            app_state.git_manager.pull_from(remote.as_str(), format!("merge-requests/{}/head", mr.iid).as_str())?;
+        } else {
+            app_state.git_manager.fetch(remote.as_str(), format!("merge-requests/{}/head:{}", mr.iid, branch).as_str())?;
+            app_state.git_manager.raw_checkout(branch.to_string(), false)?;
+        }
+
+        Printer::print_result(&json!({ "mr": mr.iid, "branch": branch }));
+    }
+
+    Ok(())
+}
+
+/// Ссылки merge-requests/* есть только в проекте MR. Если origin - форк, берем адрес целевого проекта
+/// с той же схемой (ssh или https), что и у origin
+fn get_mr_remote(app_state: &AppState, project_config: &ProjectConfig) -> Result<String, AppError> {
+    let mr_project_id = project_config.get_mr_project_id();
+    if mr_project_id == project_config.project_id {
+        return Ok("origin".to_string());
+    }
+
+    let project = app_state.gitlab_manager.get_project(mr_project_id.to_string())?;
+    let origin = app_state.git_manager.get_repo_url()?;
+
+    if origin.starts_with("http") {
+        Ok(project.http_url_to_repo)
+    } else {
+        Ok(project.ssh_url_to_repo)
+    }
+}
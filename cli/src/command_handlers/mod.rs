@@ -3,11 +3,51 @@ pub mod update_token;
 pub mod checkout_feature;
 pub mod checkout_task;
 pub mod create_mr;
+pub mod mr_description;
+pub mod manage_mr;
+pub mod mr_comments;
 pub mod update_host;
+pub mod update_jira;
+pub mod update_language;
+pub mod list_tasks;
+pub mod logs;
+pub mod completions;
+pub mod help_gen;
+pub mod ui;
+pub mod open;
+pub mod epic_tree;
+pub mod create_task;
+pub mod workflow;
+pub mod done;
+pub mod stack;
+pub mod sync;
+pub mod cleanup;
+pub mod time;
+pub mod commit;
+pub mod hooks;
 
 pub use init::init_command;
 pub use update_token::update_token_command;
 pub use checkout_feature::checkout_feature_command;
 pub use checkout_task::checkout_task_command;
 pub use create_mr::create_mr_command;
-pub use update_host::update_host_command;
\ No newline at end of file
+pub use update_host::update_host_command;
+pub use update_jira::update_jira_command;
+pub use update_language::update_language_command;
+pub use list_tasks::list_tasks_command;
+pub use logs::logs_command;
+pub use completions::{completions_command, refresh_completions_command};
+pub use help_gen::help_gen_command;
+pub use ui::ui_command;
+pub use open::{hold_clipboard_command, open_command};
+pub use epic_tree::epic_tree_command;
+pub use create_task::create_task_command;
+pub use done::done_command;
+pub use manage_mr::{approve_mr_command, checkout_mr_command, list_mrs_command, merge_mr_command, rebase_mr_command, set_mr_state_command, view_mr_command};
+pub use mr_comments::{mr_comments_command, note_command, reply_command, resolve_command};
+pub use stack::{stack_mr_command, stack_new_command, stack_rebase_command, stack_retarget_command, stack_set_parent_command, stack_show_command};
+pub use sync::{sync_abort_command, sync_command, sync_continue_command};
+pub use cleanup::cleanup_command;
+pub use time::{time_log_command, time_report_command, time_start_command, time_stop_command};
+pub use commit::{commit_command, commit_msg_hook, prepare_commit_msg_hook};
+pub use hooks::{hooks_install_command, hooks_status_command, hooks_uninstall_command, post_checkout_hook, pre_push_hook};
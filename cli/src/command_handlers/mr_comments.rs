@@ -0,0 +1,178 @@
+use std::collections::BTreeMap;
+use serde::Serialize;
+use helpers::{t, edit_text, AppError, ErrorCode, MrIid, Printer, ProjectConfig};
+use managers::gitlab::structs::{GlDiff, GlDiscussion, GlNote};
+use crate::command_handlers::manage_mr::resolve_mr;
+use crate::structs::AppState;
+use serde_json::json;
+
+const CONTEXT_RADIUS: u64 = 3;
+
+#[derive(Serialize)]
+struct DiscussionView<'a> {
+    id: &'a str,
+    resolved: bool,
+    path: Option<String>,
+    line: Option<u64>,
+    context: Vec<String>,
+    notes: Vec<&'a GlNote>,
+}
+
+pub fn mr_comments_command(app_state: &AppState, project_config: &ProjectConfig, iid: Option<MrIid>, all: bool) -> Result<(), AppError> {
+    let project_id = project_config.get_mr_project_id();
+    let mr = resolve_mr(app_state, project_config, iid)?;
+
+    let discussions = app_state.gitlab_manager.get_mr_discussions(project_id, mr.iid)?;
+    let diffs = app_state.gitlab_manager.get_mr_diffs(project_id, mr.iid)?;
+
+    let views: Vec<DiscussionView> = discussions.iter()
+        .filter(|d| !d.notes.iter().all(|n| n.system))
+        .filter(|d| all || (d.is_resolvable() && !d.is_resolved()))
+        .map(|d| get_discussion_view(d, &diffs))
+        .collect();
+
+    if Printer::is_structured() {
+        Printer::print_result(&views);
+        return Ok(());
+    }
+
+    Printer::print_info(format!("MR !{} {} ({})", mr.iid, mr.title, mr.web_url), None);
+
+    if views.is_empty() {
+        Printer::print_success(t!("mr-comments-none"), None);
+        return Ok(());
+    }
+
+    // Сначала общие обсуждения, затем по файлам и строкам
+    let mut by_file: BTreeMap<String, Vec<&DiscussionView>> = BTreeMap::new();
+    for view in &views {
+        by_file.entry(view.path.to_owned().unwrap_or_default()).or_default().push(view);
+    }
+
+    for (path, mut file_views) in by_file {
+        file_views.sort_by_key(|v| v.line);
+
+        Printer::print(String::new(), None);
+        Printer::print(if path.is_empty() { t!("mr-comments-general") } else { path }, None);
+
+        for view in file_views {
+            print_discussion(view);
+        }
+    }
+
+    Ok(())
+}
+
+pub fn reply_command(app_state: &AppState, project_config: &ProjectConfig, discussion: &str, body: &Option<String>, iid: Option<MrIid>, resolve: bool) -> Result<(), AppError> {
+    let mr = resolve_mr(app_state, project_config, iid)?;
+    let body = get_body(body)?;
+
+    if app_state.plan(t!("dry-run-reply", iid = mr.iid, discussion = discussion)) {
+        Printer::print_result(&json!({ "mr": mr.iid, "discussion": discussion, "body": body, "dry_run": true }));
+        if resolve {
+            resolve_command(app_state, project_config, discussion, Some(mr.iid), false)?;
+        }
+        return Ok(());
+    }
+
+    let note = app_state.gitlab_manager.reply_to_discussion(project_config.get_mr_project_id(), mr.iid, discussion.to_string(), body)?;
+    Printer::print_success(t!("mr-reply-added", discussion = discussion), None);
+    Printer::print_result(&json!({ "mr": mr.iid, "discussion": discussion, "note": note }));
+
+    if resolve {
+        resolve_command(app_state, project_config, discussion, Some(mr.iid), false)?;
+    }
+
+    Ok(())
+}
+
+pub fn resolve_command(app_state: &AppState, project_config: &ProjectConfig, discussion: &str, iid: Option<MrIid>, unresolve: bool) -> Result<(), AppError> {
+    let mr = resolve_mr(app_state, project_config, iid)?;
+
+    let plan = if unresolve { t!("dry-run-unresolve", iid = mr.iid, discussion = discussion) } else { t!("dry-run-resolve", iid = mr.iid, discussion = discussion) };
+    if app_state.plan(plan) {
+        Printer::print_result(&json!({ "mr": mr.iid, "discussion": discussion, "resolved": !unresolve, "dry_run": true }));
+        return Ok(());
+    }
+
+    let updated = app_state.gitlab_manager.resolve_discussion(project_config.get_mr_project_id(), mr.iid, discussion.to_string(), !unresolve)?;
+    Printer::print_result(&json!({ "mr": mr.iid, "discussion": updated }));
+
+    if unresolve {
+        Printer::print_success(t!("mr-discussion-unresolved", discussion = discussion), None);
+    } else {
+        Printer::print_success(t!("mr-discussion-resolved", discussion = discussion), None);
+    }
+
+    Ok(())
+}
+
+pub fn note_command(app_state: &AppState, project_config: &ProjectConfig, body: &Option<String>, iid: Option<MrIid>) -> Result<(), AppError> {
+    let mr = resolve_mr(app_state, project_config, iid)?;
+    let body = get_body(body)?;
+
+    if app_state.plan(t!("dry-run-note", iid = mr.iid)) {
+        Printer::print_result(&json!({ "mr": mr.iid, "body": body, "dry_run": true }));
+        return Ok(());
+    }
+
+    let note = app_state.gitlab_manager.create_mr_note(project_config.get_mr_project_id(), mr.iid, body)?;
+
+    Printer::print_success(t!("mr-note-added", url = format!("{}#note_{}", mr.web_url, note.id)), None);
+    Printer::print_result(&json!({ "mr": mr.iid, "note": note }));
+
+    Ok(())
+}
+
+fn get_body(body: &Option<String>) -> Result<String, AppError> {
+    let body = match body {
+        Some(b) => b.to_string(),
+        None => edit_text("", "mr-comment.md")?,
+    };
+
+    let body = body.trim().to_string();
+    if body.is_empty() {
+        return Err(AppError::new(ErrorCode::InvalidArguments, t!("mr-comment-empty")));
+    }
+
+    Ok(body)
+}
+
+fn get_discussion_view<'a>(discussion: &'a GlDiscussion, diffs: &[GlDiff]) -> DiscussionView<'a> {
+    let position = discussion.get_position();
+
+    let context = match position {
+        Some(p) => diffs.iter()
+            .find(|d| Some(&d.new_path) == p.new_path.as_ref() || Some(&d.old_path) == p.old_path.as_ref())
+            .map(|d| d.get_context(p.new_line, p.old_line, CONTEXT_RADIUS))
+            .unwrap_or_default(),
+        None => vec![],
+    };
+
+    DiscussionView {
+        id: discussion.id.as_str(),
+        resolved: discussion.is_resolved(),
+        path: position.map(|p| p.get_path()),
+        line: position.and_then(|p| p.new_line.or(p.old_line)),
+        context,
+        notes: discussion.notes.iter().filter(|n| !n.system).collect(),
+    }
+}
+
+fn print_discussion(view: &DiscussionView) {
+    let line = view.line.map(|l| format!("{} ", t!("mr-comments-line", line = l))).unwrap_or_default();
+    let state = if view.resolved { format!(" [{}]", t!("mr-comments-resolved")) } else { String::new() };
+
+    Printer::print(format!("  ── {}{}{}", line, t!("mr-comments-discussion", id = view.id), state), None);
+
+    for context_line in &view.context {
+        Printer::print(format!("  │ {}", context_line), None);
+    }
+
+    for note in &view.notes {
+        Printer::print(format!("  @{} ({}):", note.author.username, note.created_at), None);
+        for body_line in note.body.lines() {
+            Printer::print(format!("    {}", body_line), None);
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use dialoguer::FuzzySelect;
+use minijinja::Environment;
+use serde::Serialize;
+use helpers::{t, AppError, ErrorCode, LogError, ProjectConfig};
+use managers::git::GitCommit;
+use managers::tracker::{Feature, Task};
+use crate::structs::AppState;
+
+const GITLAB_TEMPLATES_DIR: &str = ".gitlab/merge_request_templates";
+const DEFAULT_TEMPLATE: &str = "{% if issue %}{{ issue.reference }}{% endif %}";
+
+#[derive(Serialize)]
+struct IssueContext {
+    key: String,
+    title: String,
+    description: String,
+    labels: Vec<String>,
+    web_url: String,
+    reference: String,
+}
+
+#[derive(Serialize)]
+struct EpicContext {
+    key: String,
+    title: String,
+    web_url: String,
+    labels: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct MrTemplateContext {
+    issue: Option<IssueContext>,
+    epic: Option<EpicContext>,
+    closes: String,
+    commits: Vec<GitCommit>,
+    changed_files: Vec<String>,
+    source_branch: String,
+    target_branch: String,
+}
+
+/// Собирает описание MR из шаблона.
+/// `template`: `None` - шаблон из конфига или Default.md, `Some("")` - выбрать из .gitlab/merge_request_templates
+pub fn render_mr_description(
+    app_state: &AppState,
+    project_config: &ProjectConfig,
+    template: &Option<String>,
+    task: Option<&Task>,
+    epic: Option<&Feature>,
+    source_branch: &str,
+    target_branch: &str,
+) -> Result<String, AppError> {
+    let template_source = load_template(app_state, project_config, template)?;
+
+    let base = app_state.git_manager.get_existed_branch(target_branch.to_string()).unwrap_or(target_branch.to_string());
+
+    let context = MrTemplateContext {
+        issue: task.map(|t| IssueContext {
+            key: t.key.to_string(),
+            title: t.title.to_string(),
+            description: t.description.to_owned().unwrap_or_default(),
+            labels: t.labels.to_vec(),
+            web_url: t.web_url.to_string(),
+            reference: t.get_mr_reference(),
+        }),
+        epic: epic.map(|e| EpicContext {
+            key: e.key.to_string(),
+            title: e.title.to_string(),
+            web_url: e.web_url.to_string(),
+            labels: e.labels.to_vec(),
+        }),
+        closes: task.map(|t| t.get_closing_reference()).unwrap_or_default(),
+        commits: app_state.git_manager.get_commits(base.as_str(), source_branch).unwrap_or_default(),
+        changed_files: app_state.git_manager.get_changed_files(base.as_str(), source_branch).unwrap_or_default(),
+        source_branch: source_branch.to_string(),
+        target_branch: target_branch.to_string(),
+    };
+
+    let mut env = Environment::new();
+    env.add_template("mr", template_source.as_str()).map_err(|err| AppError::new(ErrorCode::Config, t!("mr-template-error", error = err)))?;
+
+    let template = env.get_template("mr").log_error()?;
+    let description = template.render(context).map_err(|err| AppError::new(ErrorCode::Config, t!("mr-template-error", error = err)))?;
+
+    Ok(description.trim().to_string())
+}
+
+fn load_template(app_state: &AppState, project_config: &ProjectConfig, template: &Option<String>) -> Result<String, AppError> {
+    let templates_dir = app_state.path.join(GITLAB_TEMPLATES_DIR);
+
+    let path = match template {
+        Some(name) if name.is_empty() => Some(select_template(&templates_dir)?),
+        Some(name) => {
+            let by_name = templates_dir.join(format!("{}.md", name));
+            if by_name.exists() { Some(by_name) } else { Some(app_state.path.join(name)) }
+        }
+        None => match &project_config.mr_template {
+            Some(path) => Some(app_state.path.join(path)),
+            None => {
+                let default = templates_dir.join("Default.md");
+                if default.exists() { Some(default) } else { None }
+            }
+        }
+    };
+
+    match path {
+        Some(path) => fs::read_to_string(&path).map_err(|err| AppError::not_found(t!("mr-template-read-failed", path = path.display(), error = err))),
+        None => Ok(DEFAULT_TEMPLATE.to_string()),
+    }
+}
+
+fn select_template(templates_dir: &Path) -> Result<PathBuf, AppError> {
+    let mut templates: Vec<PathBuf> = match fs::read_dir(templates_dir) {
+        Ok(entries) => entries
+            .filter_map(|e| e.ok().map(|e| e.path()))
+            .filter(|p| p.extension().is_some_and(|ext| ext == "md"))
+            .collect(),
+        Err(_) => vec![],
+    };
+    templates.sort();
+
+    if templates.is_empty() {
+        return Err(AppError::not_found(t!("mr-templates-not-found", dir = GITLAB_TEMPLATES_DIR)));
+    }
+
+    let names: Vec<String> = templates.iter()
+        .map(|p| p.file_stem().unwrap_or_default().to_string_lossy().to_string())
+        .collect();
+
+    let selection = FuzzySelect::new()
+        .with_prompt(t!("mr-template-prompt"))
+        .items(&names)
+        .default(0)
+        .interact()
+        .log_error()?;
+
+    Ok(templates[selection].to_path_buf())
+}
@@ -0,0 +1,105 @@
+use std::io::{Read, Write};
+use std::process::{Command, Stdio};
+use helpers::{t, AppError, LogError, Printer, ProjectConfig};
+use managers::tracker::FeatureKey;
+use crate::command_handlers::manage_mr::resolve_mr;
+use crate::commands::OpenTarget;
+use crate::structs::AppState;
+use serde_json::json;
+
+pub fn open_command(app_state: &AppState, project_config: &ProjectConfig, target: OpenTarget, print: bool, copy: bool) -> Result<(), AppError> {
+    let url = get_target_url(app_state, project_config, target)?;
+
+    if copy {
+        copy_to_clipboard(url.as_str())?;
+        Printer::print_success(t!("open-copied", url = url), None);
+    }
+
+    if print {
+        Printer::print(url.to_string(), None);
+    } else if !copy {
+        Printer::print_info(t!("open-opening", url = url), None);
+        open::that_detached(url.as_str()).log_error()?;
+    }
+
+    Printer::print_result(&json!({ "target": target, "url": url }));
+
+    Ok(())
+}
+
+/// Держит текст из stdin в буфере обмена, пока его не заменят
+pub fn hold_clipboard_command() -> Result<(), AppError> {
+    let mut text = String::new();
+    std::io::stdin().read_to_string(&mut text).log_error()?;
+
+    #[cfg(target_os = "linux")]
+    {
+        use arboard::SetExtLinux;
+        arboard::Clipboard::new().log_error()?.set().wait().text(text).log_error()?;
+    }
+    #[cfg(not(target_os = "linux"))]
+    arboard::Clipboard::new().log_error()?.set_text(text).log_error()?;
+
+    Ok(())
+}
+
+/// В Linux буфер обмена живет, пока жив процесс-владелец, поэтому текст держит фоновый `gw hold-clipboard`
+fn copy_to_clipboard(text: &str) -> Result<(), AppError> {
+    if !cfg!(target_os = "linux") {
+        arboard::Clipboard::new().log_error()?.set_text(text).log_error()?;
+        return Ok(());
+    }
+
+    // Ошибку доступа к дисплею показываем сразу, фоновый процесс молчит
+    arboard::Clipboard::new().log_error()?;
+
+    let mut child = Command::new(std::env::current_exe().log_error()?)
+        .arg("hold-clipboard")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .current_dir("/")
+        .spawn()
+        .log_error()?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(text.as_bytes()).log_error()?;
+    }
+
+    Ok(())
+}
+
+/// Адрес объекта по текущей ветке
+fn get_target_url(app_state: &AppState, project_config: &ProjectConfig, target: OpenTarget) -> Result<String, AppError> {
+    match target {
+        OpenTarget::Issue => {
+            let task = app_state.get_task_key(&None)?;
+            let tracker = app_state.get_task_tracker(&task, project_config)?;
+
+            Ok(tracker.get_task(&task)?.web_url)
+        }
+        OpenTarget::Epic => {
+            let branch = app_state.git_manager.get_current_branch()?;
+
+            // На ветке фичи эпик берется из имени ветки, на ветке задачи - собственный эпик задачи
+            let feature = match branch.strip_prefix("feature/") {
+                Some(key) => FeatureKey::parse(key)?,
+                None => {
+                    let task = app_state.get_task_key(&None)?;
+                    let tracker = app_state.get_task_tracker(&task, project_config)?;
+                    tracker.get_task(&task)?.feature.ok_or(AppError::not_found(t!("open-epic-not-found", task = task)))?
+                }
+            };
+            let tracker = app_state.get_feature_tracker(&feature, project_config)?;
+
+            Ok(tracker.get_feature(&feature)?.web_url)
+        }
+        OpenTarget::Mr => Ok(resolve_mr(app_state, project_config, None)?.web_url),
+        OpenTarget::Pipeline => {
+            let mr = resolve_mr(app_state, project_config, None)?;
+
+            mr.head_pipeline.map(|p| p.web_url).ok_or(AppError::not_found(t!("open-pipeline-not-found", iid = mr.iid)))
+        }
+        OpenTarget::Project => Ok(app_state.gitlab_manager.get_project(project_config.project_id.to_string())?.web_url),
+    }
+}
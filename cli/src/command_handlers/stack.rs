@@ -0,0 +1,458 @@
+use std::collections::{HashMap, HashSet};
+use serde::Serialize;
+use helpers::{t, AppError, ErrorCode, Printer, ProjectConfig};
+use managers::gitlab::structs::{GlMergeRequest, GlMergeRequestState, GlMergeRequestsFilter};
+use managers::tracker::TaskKey;
+use crate::command_handlers::create_mr_command;
+use crate::command_handlers::workflow::{apply_workflow_step, WorkflowStage};
+use crate::commands::CreateMrArgs;
+use crate::structs::AppState;
+use serde_json::json;
+
+/// Ветка стека и ее родитель, в порядке от корня к листьям
+#[derive(Serialize)]
+pub struct StackEntry {
+    pub branch: String,
+    pub parent: String,
+    pub depth: usize,
+}
+
+pub fn stack_show_command(app_state: &AppState, project_config: &ProjectConfig) -> Result<(), AppError> {
+    let current = app_state.git_manager.get_current_branch()?;
+    let parents = get_parents(app_state)?;
+    let (root, entries) = get_stack(&parents, current.as_str());
+
+    if entries.is_empty() {
+        Printer::print_warning(t!("stack-branch-not-in-stack-hint", branch = current), None);
+        return Ok(());
+    }
+
+    let mrs = get_open_mrs(app_state, project_config)?;
+
+    if Printer::is_structured() {
+        let branches: Vec<_> = entries.iter()
+            .map(|e| json!({ "branch": e.branch, "parent": e.parent, "depth": e.depth, "mr": mrs.get(&e.branch) }))
+            .collect();
+        Printer::print_result(&json!({ "root": root, "current": current, "branches": branches }));
+        return Ok(());
+    }
+
+    Printer::print(format!("{}{}", root, if root == current { " *" } else { "" }), None);
+
+    for entry in &entries {
+        let marker = if entry.branch == current { " *" } else { "" };
+        let mr = match mrs.get(&entry.branch) {
+            Some(mr) if mr.target_branch != entry.parent => format!(" !{} ({})", mr.iid, t!("stack-mr-wrong-target", target = mr.target_branch, expected = entry.parent)),
+            Some(mr) => format!(" !{}", mr.iid),
+            None => format!(" ({})", t!("stack-no-mr")),
+        };
+
+        Printer::print(format!("{}└── {}{}{}", "    ".repeat(entry.depth - 1), entry.branch, marker, mr), None);
+    }
+
+    Ok(())
+}
+
+/// Ветка задачи от текущей ветки, а не от ветки фичи
+pub fn stack_new_command(app_state: &AppState, project_config: &ProjectConfig, task: &TaskKey) -> Result<(), AppError> {
+    if app_state.git_manager.is_dirty()? {
+        return Err(AppError::dirty_repository(t!("repository-dirty")));
+    };
+
+    let parent = app_state.git_manager.get_current_branch()?;
+    let branch = task.get_branch_name();
+
+    if app_state.git_manager.get_existed_branch(branch.to_string()).is_ok() {
+        return Err(AppError::new(ErrorCode::Conflict, t!("stack-branch-exists", branch = branch)));
+    }
+
+    let tracker = app_state.get_task_tracker(task, project_config)?;
+    let issue = tracker.get_task(task)?;
+
+    Printer::print_info(t!("checkout-task-issue", title = issue.title, url = issue.web_url), None);
+
+    app_state.git_manager.raw_checkout(branch.to_string(), true)?;
+    set_branch_parent(app_state, branch.as_str(), parent.as_str())?;
+
+    Printer::print_success(t!("stack-branch-created", branch = branch, parent = parent), None);
+    Printer::print_result(&json!({ "task": issue, "branch": branch, "parent": parent }));
+
+    if let Err(err) = apply_workflow_step(app_state, project_config, task, WorkflowStage::InProgress) {
+        Printer::print_warning(t!("workflow-update-failed", error = err), None);
+    }
+
+    Ok(())
+}
+
+pub fn stack_set_parent_command(app_state: &AppState, parent: &str, branch: &Option<String>) -> Result<(), AppError> {
+    let branch = match branch {
+        Some(b) => b.to_string(),
+        None => app_state.git_manager.get_current_branch()?,
+    };
+
+    app_state.git_manager.get_existed_branch(parent.to_string())?;
+
+    // Родитель не может быть потомком ветки
+    let parents = get_parents(app_state)?;
+    let mut ancestor = Some(parent.to_string());
+    while let Some(a) = ancestor {
+        if a == branch {
+            return Err(AppError::new(ErrorCode::InvalidArguments, t!("stack-parent-cycle", branch = branch, parent = parent)));
+        }
+        ancestor = parents.get(&a).cloned();
+    }
+
+    set_branch_parent(app_state, branch.as_str(), parent)?;
+
+    Printer::print_success(t!("stack-parent-set", branch = branch, parent = parent), None);
+    Printer::print_result(&json!({ "branch": branch, "parent": parent }));
+
+    Ok(())
+}
+
+pub fn stack_rebase_command(app_state: &AppState, push: bool) -> Result<(), AppError> {
+    if app_state.git_manager.is_dirty()? {
+        return Err(AppError::dirty_repository(t!("repository-dirty")));
+    };
+
+    let current = app_state.git_manager.get_current_branch()?;
+    let parents = get_parents(app_state)?;
+    let (_, entries) = get_stack(&parents, current.as_str());
+
+    if entries.is_empty() {
+        return Err(AppError::not_found(t!("stack-branch-not-in-stack", branch = current)));
+    }
+
+    for entry in &entries {
+        let onto = app_state.git_manager.get_existed_branch(entry.parent.to_string())?;
+
+        Printer::print_info(t!("rebase-branch", branch = entry.branch, onto = onto), None);
+
+        app_state.git_manager.rebase(onto.as_str(), entry.branch.as_str()).map_err(|err| {
+            AppError::new(ErrorCode::Conflict, t!("stack-rebase-conflict", branch = entry.branch, onto = onto, error = err))
+        })?;
+
+        if push {
+            app_state.git_manager.push(entry.branch.as_str(), true)?;
+        }
+    }
+
+    app_state.git_manager.raw_checkout(current, false)?;
+
+    Printer::print_success(t!("stack-rebased"), None);
+    Printer::print_result(&entries);
+
+    Ok(())
+}
+
+/// MR для каждой ветки стека в ее родителя
+pub fn stack_mr_command(app_state: &AppState, project_config: &ProjectConfig, draft: bool) -> Result<(), AppError> {
+    let current = app_state.git_manager.get_current_branch()?;
+    let parents = get_parents(app_state)?;
+    let (_, entries) = get_stack(&parents, current.as_str());
+
+    if entries.is_empty() {
+        return Err(AppError::not_found(t!("stack-branch-not-in-stack", branch = current)));
+    }
+
+    let mrs = get_open_mrs(app_state, project_config)?;
+
+    for entry in &entries {
+        if let Some(mr) = mrs.get(&entry.branch) {
+            if mr.target_branch == entry.parent {
+                Printer::print_info(t!("stack-mr-exists", branch = entry.branch, iid = mr.iid), None);
+            } else {
+                Printer::print_warning(t!("stack-mr-retarget-hint", iid = mr.iid, target = mr.target_branch, expected = entry.parent), None);
+            }
+            continue;
+        }
+
+        app_state.git_manager.push(entry.branch.as_str(), false)?;
+
+        create_mr_command(app_state, project_config, &CreateMrArgs {
+            source: Some(entry.branch.to_string()),
+            target: Some(entry.parent.to_string()),
+            draft,
+            ..Default::default()
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Перенаправляет MR веток, чьи родители уже смержены, на следующего предка
+pub fn stack_retarget_command(app_state: &AppState, project_config: &ProjectConfig) -> Result<(), AppError> {
+    let current = app_state.git_manager.get_current_branch()?;
+    let mut parents = get_parents(app_state)?;
+    let (_, entries) = get_stack(&parents, current.as_str());
+
+    let mut retargeted = false;
+
+    for entry in &entries {
+        let filter = GlMergeRequestsFilter {
+            source_branch: Some(entry.parent.to_string()),
+            state: Some(GlMergeRequestState::Merged),
+            ..Default::default()
+        };
+
+        // Старые MR из той же ветки (например, прошлые MR ветки фичи) не считаются: смержена должна быть текущая голова
+        let head = match app_state.git_manager.get_existed_branch(entry.parent.to_string())
+            .and_then(|b| app_state.git_manager.get_commit_sha(b.as_str())) {
+            Ok(sha) => sha,
+            Err(_) => continue,
+        };
+
+        let merged = match app_state.gitlab_manager.get_mrs(project_config.get_mr_project_id(), &filter)?.into_iter()
+            .find(|mr| mr.sha.as_deref() == Some(head.as_str())) {
+            Some(mr) => mr,
+            None => continue,
+        };
+
+        let new_parent = parents.get(&entry.parent).cloned().unwrap_or(merged.target_branch);
+
+        retarget_children(app_state, project_config, entry.parent.as_str(), new_parent.as_str())?;
+        parents.insert(entry.branch.to_string(), new_parent);
+        retargeted = true;
+    }
+
+    if retargeted {
+        Printer::print_info(t!("stack-rebase-hint"), None);
+    } else {
+        Printer::print_info(t!("stack-no-merged-parents"), None);
+    }
+
+    Ok(())
+}
+
+/// Переносит открытые MR и локальные ветки с смерженной ветки на ее цель
+pub fn retarget_children(app_state: &AppState, project_config: &ProjectConfig, merged_branch: &str, new_target: &str) -> Result<(), AppError> {
+    let project_id = project_config.get_mr_project_id();
+
+    let filter = GlMergeRequestsFilter {
+        target_branch: Some(merged_branch.to_string()),
+        state: Some(GlMergeRequestState::Opened),
+        ..Default::default()
+    };
+
+    for mr in app_state.gitlab_manager.get_mrs(project_id, &filter)? {
+        if app_state.plan(t!("dry-run-retarget-mr", iid = mr.iid, branch = mr.source_branch, target = new_target)) {
+            Printer::print_result(&json!({ "mr": mr.iid, "source_branch": mr.source_branch, "target_branch": new_target, "dry_run": true }));
+            continue;
+        }
+
+        app_state.gitlab_manager.update_mr_target(project_id, mr.iid, new_target.to_string())?;
+        Printer::print_success(t!("stack-mr-retargeted", iid = mr.iid, branch = mr.source_branch, target = new_target), None);
+        Printer::print_result(&json!({ "mr": mr.iid, "source_branch": mr.source_branch, "target_branch": new_target }));
+    }
+
+    for (branch, parent) in get_parents(app_state)? {
+        if parent == merged_branch {
+            set_branch_parent(app_state, branch.as_str(), new_target)?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn set_branch_parent(app_state: &AppState, branch: &str, parent: &str) -> Result<(), AppError> {
+    app_state.git_manager.set_config(format!("branch.{}.gw-parent", branch).as_str(), parent)
+}
+
+pub fn get_parents(app_state: &AppState) -> Result<HashMap<String, String>, AppError> {
+    let values = app_state.git_manager.get_config_values(r"^branch\..*\.gw-parent$")?;
+
+    Ok(values.into_iter().filter_map(|(key, parent)| {
+        key.strip_prefix("branch.")
+            .and_then(|k| k.strip_suffix(".gw-parent"))
+            .map(|branch| (branch.to_string(), parent))
+    }).collect())
+}
+
+/// Корень стека и ветки стека в порядке обхода от корня: цепочка предков ветки и ее потомки.
+/// Корень (основная ветка или ветка фичи) в стек не входит, соседние ветки от того же корня тоже
+pub fn get_stack(parents: &HashMap<String, String>, branch: &str) -> (String, Vec<StackEntry>) {
+    let mut chain = vec![];
+    let mut root = branch.to_string();
+    let mut visited = HashSet::new();
+    while let Some(parent) = parents.get(&root) {
+        // Цикл в родителях: у ветки нет корня, стек не определен
+        if !visited.insert(root.to_string()) {
+            return (branch.to_string(), vec![]);
+        }
+        chain.push(root.to_string());
+        root = parent.to_string();
+    }
+
+    // Ветка без родителя сама является корнем
+    if chain.is_empty() {
+        return (root, vec![]);
+    }
+
+    let mut entries: Vec<StackEntry> = chain.iter().rev().enumerate()
+        .map(|(i, b)| StackEntry { branch: b.to_string(), parent: parents[b].to_string(), depth: i + 1 })
+        .collect();
+
+    let depth = entries.len();
+    entries.extend(get_descendants(parents, branch).into_iter().map(|e| StackEntry { depth: e.depth + depth, ..e }));
+
+    (root, entries)
+}
+
+/// Все потомки ветки в порядке обхода в глубину, глубина считается от нее. Нужны для корня,
+/// у которого нет своего родителя (например, ветки фичи при sync)
+pub fn get_descendants(parents: &HashMap<String, String>, root: &str) -> Vec<StackEntry> {
+    let mut children: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (child, parent) in parents {
+        children.entry(parent.as_str()).or_default().push(child.as_str());
+    }
+
+    let mut entries = vec![];
+    let mut visited = HashSet::from([root.to_string()]);
+    let mut queue = vec![(root.to_string(), 0)];
+
+    while let Some((parent, depth)) = queue.pop() {
+        let mut branch_children = children.get(parent.as_str()).cloned().unwrap_or_default();
+        branch_children.sort();
+        for child in branch_children.into_iter().rev() {
+            if visited.insert(child.to_string()) {
+                queue.push((child.to_string(), depth + 1));
+            }
+        }
+
+        if parent != root {
+            entries.push(StackEntry { branch: parent.to_string(), parent: parents[&parent].to_string(), depth });
+        }
+    }
+
+    entries
+}
+
+fn get_open_mrs(app_state: &AppState, project_config: &ProjectConfig) -> Result<HashMap<String, GlMergeRequest>, AppError> {
+    let filter = GlMergeRequestsFilter {
+        state: Some(GlMergeRequestState::Opened),
+        ..Default::default()
+    };
+
+    let mrs = app_state.gitlab_manager.get_mrs(project_config.get_mr_project_id(), &filter)?;
+
+    Ok(mrs.into_iter().map(|mr| (mr.source_branch.to_string(), mr)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parents(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(b, p)| (b.to_string(), p.to_string())).collect()
+    }
+
+    fn branches(entries: &[StackEntry]) -> Vec<(&str, &str, usize)> {
+        entries.iter().map(|e| (e.branch.as_str(), e.parent.as_str(), e.depth)).collect()
+    }
+
+    #[test]
+    fn stack_contains_chain_and_descendants() {
+        let parents = parents(&[
+            ("1-task", "feature/10"),
+            ("2-task", "1-task"),
+            ("3-task", "2-task"),
+            ("4-task", "2-task"),
+        ]);
+
+        let (root, entries) = get_stack(&parents, "2-task");
+
+        assert_eq!(root, "feature/10");
+        assert_eq!(branches(&entries), vec![
+            ("1-task", "feature/10", 1),
+            ("2-task", "1-task", 2),
+            ("3-task", "2-task", 3),
+            ("4-task", "2-task", 3),
+        ]);
+    }
+
+    #[test]
+    fn stack_excludes_siblings_from_same_root() {
+        let parents = parents(&[
+            ("1-task", "master"),
+            ("2-task", "master"),
+            ("3-task", "1-task"),
+            ("4-task", "2-task"),
+        ]);
+
+        let (root, entries) = get_stack(&parents, "3-task");
+
+        assert_eq!(root, "master");
+        assert_eq!(branches(&entries), vec![("1-task", "master", 1), ("3-task", "1-task", 2)]);
+    }
+
+    #[test]
+    fn stack_excludes_siblings_in_chain() {
+        let parents = parents(&[
+            ("1-task", "master"),
+            ("2-task", "1-task"),
+            ("3-task", "1-task"),
+        ]);
+
+        let (_, entries) = get_stack(&parents, "2-task");
+
+        assert_eq!(branches(&entries), vec![("1-task", "master", 1), ("2-task", "1-task", 2)]);
+    }
+
+    #[test]
+    fn descendants_of_root_without_parent() {
+        let parents = parents(&[
+            ("1-task", "feature/10"),
+            ("2-task", "feature/10"),
+            ("3-task", "1-task"),
+            ("4-task", "master"),
+        ]);
+
+        assert_eq!(branches(&get_descendants(&parents, "feature/10")), vec![
+            ("1-task", "feature/10", 1),
+            ("3-task", "1-task", 2),
+            ("2-task", "feature/10", 1),
+        ]);
+    }
+
+    #[test]
+    fn root_branch_is_not_a_stack() {
+        let parents = parents(&[("1-task", "master"), ("2-task", "master")]);
+
+        let (root, entries) = get_stack(&parents, "master");
+
+        assert_eq!(root, "master");
+        assert!(entries.is_empty());
+    }
+
+    #[test]
+    fn branch_without_parent_is_not_a_stack() {
+        let (root, entries) = get_stack(&HashMap::new(), "5-task");
+
+        assert_eq!(root, "5-task");
+        assert!(entries.is_empty());
+    }
+
+    #[test]
+    fn cycle_in_ancestors_is_not_a_stack() {
+        let parents = parents(&[("1-task", "2-task"), ("2-task", "1-task")]);
+
+        let (root, entries) = get_stack(&parents, "1-task");
+
+        assert_eq!(root, "1-task");
+        assert!(entries.is_empty());
+    }
+
+    #[test]
+    fn cycle_in_descendants_is_visited_once() {
+        let parents = parents(&[
+            ("1-task", "master"),
+            ("2-task", "1-task"),
+            ("3-task", "4-task"),
+            ("4-task", "3-task"),
+        ]);
+
+        let (_, entries) = get_stack(&parents, "1-task");
+
+        assert_eq!(branches(&entries), vec![("1-task", "master", 1), ("2-task", "1-task", 2)]);
+    }
+}
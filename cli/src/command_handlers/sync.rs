@@ -0,0 +1,238 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+use helpers::{t, AppError, ErrorCode, LogError, Printer, ProjectConfig, SyncStrategy, WithCode};
+use managers::tracker::TaskKey;
+use crate::command_handlers::stack::{get_descendants, get_parents};
+use crate::structs::AppState;
+use serde_json::json;
+
+const SYNC_STATE_FILE: &str = "gw-sync.json";
+
+/// Прерванная конфликтом синхронизация, хранится в .git
+#[derive(Serialize, Deserialize)]
+struct SyncState {
+    strategy: SyncStrategy,
+    original_branch: String,
+    steps: Vec<SyncStep>,
+    current: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SyncStep {
+    branch: String,
+    parent: String,
+}
+
+pub fn sync_command(app_state: &AppState, project_config: &ProjectConfig, strategy: Option<SyncStrategy>) -> Result<(), AppError> {
+    if get_state_path(app_state).exists() {
+        return Err(AppError::new(ErrorCode::Conflict, t!("sync-in-progress")));
+    }
+
+    if app_state.git_manager.is_dirty()? {
+        return Err(AppError::dirty_repository(t!("repository-dirty")));
+    };
+
+    let original_branch = app_state.git_manager.get_current_branch()?;
+    let feature_branch = get_feature_branch(app_state, project_config, original_branch.as_str())?;
+    let base = update_base(app_state, project_config.sync.base_branch.as_str())?;
+
+    // Только локальные ветки задач
+    let steps = get_sync_steps(&get_parents(app_state)?, feature_branch.as_str(), base, |branch| {
+        app_state.git_manager.get_existed_branch(branch.to_string()).ok().as_deref() == Some(branch)
+    });
+
+    let state = SyncState {
+        strategy: strategy.unwrap_or(project_config.sync.strategy),
+        original_branch,
+        steps,
+        current: 0,
+    };
+
+    run_steps(app_state, state)
+}
+
+pub fn sync_continue_command(app_state: &AppState) -> Result<(), AppError> {
+    let mut state = load_state(app_state)?;
+
+    let conflicts = app_state.git_manager.get_conflicted_files()?;
+    if !conflicts.is_empty() {
+        return Err(AppError::new(ErrorCode::Conflict, t!("sync-unresolved-conflicts", files = conflicts.join(", "))));
+    }
+
+    app_state.git_manager.continue_operation(state.strategy == SyncStrategy::Rebase)?;
+    state.current += 1;
+
+    run_steps(app_state, state)
+}
+
+pub fn sync_abort_command(app_state: &AppState) -> Result<(), AppError> {
+    let state = load_state(app_state)?;
+
+    app_state.git_manager.abort_operation(state.strategy == SyncStrategy::Rebase)?;
+    app_state.git_manager.raw_checkout(state.original_branch.to_string(), false)?;
+    fs::remove_file(get_state_path(app_state)).log_error()?;
+
+    let done: Vec<&str> = state.steps[..state.current].iter().map(|s| s.branch.as_str()).collect();
+    if !done.is_empty() {
+        Printer::print_warning(t!("sync-aborted-partial", branches = done.join(", ")), None);
+    }
+
+    Printer::print_success(t!("sync-aborted"), None);
+    Printer::print_result(&json!({ "aborted": true, "synced": done }));
+
+    Ok(())
+}
+
+fn run_steps(app_state: &AppState, mut state: SyncState) -> Result<(), AppError> {
+    while state.current < state.steps.len() {
+        let step = &state.steps[state.current];
+
+        let result = match state.strategy {
+            SyncStrategy::Merge => {
+                Printer::print_info(t!("sync-merging", parent = step.parent, branch = step.branch), None);
+                app_state.git_manager.raw_checkout(step.branch.to_string(), false)?;
+                app_state.git_manager.merge(step.parent.as_str())
+            }
+            SyncStrategy::Rebase => {
+                Printer::print_info(t!("rebase-branch", branch = step.branch, onto = step.parent), None);
+                app_state.git_manager.rebase(step.parent.as_str(), step.branch.as_str())
+            }
+        };
+
+        if let Err(err) = result {
+            let conflicts = app_state.git_manager.get_conflicted_files().unwrap_or_default();
+            let message = t!("sync-step-failed", branch = step.branch, parent = step.parent, error = err);
+
+            save_state(app_state, &state)?;
+
+            if conflicts.is_empty() {
+                return Err(AppError::git(message));
+            }
+
+            Printer::print_error(message, None);
+            Printer::print(t!("sync-conflicts"), None);
+            for file in &conflicts {
+                Printer::print(format!("  {}", file), None);
+            }
+
+            return Err(AppError::new(ErrorCode::Conflict, t!("sync-resolve-hint")));
+        }
+
+        state.current += 1;
+    }
+
+    app_state.git_manager.raw_checkout(state.original_branch.to_string(), false)?;
+
+    let state_path = get_state_path(app_state);
+    if state_path.exists() {
+        fs::remove_file(state_path).log_error()?;
+    }
+
+    for step in &state.steps {
+        Printer::print_success(format!("{} ← {}", step.branch, step.parent), None);
+    }
+
+    Printer::print_result(&json!({ "strategy": state.strategy, "steps": state.steps }));
+
+    Ok(())
+}
+
+/// Ветка фичи от базовой ветки, затем ее локальные потомки от корня к листьям
+fn get_sync_steps(parents: &HashMap<String, String>, feature_branch: &str, base: String, is_local: impl Fn(&str) -> bool) -> Vec<SyncStep> {
+    let mut steps = vec![SyncStep { branch: feature_branch.to_string(), parent: base }];
+
+    for entry in get_descendants(parents, feature_branch) {
+        if is_local(entry.branch.as_str()) {
+            steps.push(SyncStep { branch: entry.branch, parent: entry.parent });
+        }
+    }
+
+    steps
+}
+
+/// Ветка фичи: текущая ветка feature/* или ветка корневого эпика задачи
+fn get_feature_branch(app_state: &AppState, project_config: &ProjectConfig, branch: &str) -> Result<String, AppError> {
+    if branch.starts_with("feature/") {
+        return Ok(branch.to_string());
+    }
+
+    let key = TaskKey::from_branch(branch).ok_or_else(|| AppError::new(ErrorCode::InvalidTaskKey, t!("sync-not-task-branch", branch = branch)))?;
+
+    let tracker = app_state.get_task_tracker(&key, project_config)?;
+    let task = tracker.get_task(&key)?;
+
+    let feature = task.feature.ok_or_else(|| AppError::not_found(t!("sync-task-without-epic", task = key)))?;
+    let feature = app_state.get_feature_tracker(&feature, project_config)?.get_root_feature(&feature)?;
+
+    if feature.is_techdebt() {
+        return Err(AppError::new(ErrorCode::Unsupported, t!("sync-tech-debt")));
+    }
+
+    Ok(feature.get_branch_name())
+}
+
+/// Обновляет базовую ветку из origin. Если локальную ветку обновить нельзя, используется origin/<base>
+fn update_base(app_state: &AppState, base: &str) -> Result<String, AppError> {
+    Printer::print_info(t!("sync-updating", branch = base), None);
+
+    if app_state.git_manager.fetch("origin", format!("{0}:{0}", base).as_str()).is_ok() {
+        return Ok(base.to_string());
+    }
+
+    app_state.git_manager.fetch("origin", base)?;
+    Printer::print_warning(t!("sync-using-remote", branch = base), None);
+
+    Ok(format!("origin/{}", base))
+}
+
+fn get_state_path(app_state: &AppState) -> PathBuf {
+    app_state.git_manager.get_git_dir().join(SYNC_STATE_FILE)
+}
+
+fn load_state(app_state: &AppState) -> Result<SyncState, AppError> {
+    let content = fs::read_to_string(get_state_path(app_state)).map_err(|_| AppError::not_found(t!("sync-no-state")))?;
+
+    serde_json::from_str(content.as_str()).with_code(ErrorCode::Config)
+}
+
+fn save_state(app_state: &AppState, state: &SyncState) -> Result<(), AppError> {
+    let content = serde_json::to_string_pretty(state).log_error()?;
+
+    fs::write(get_state_path(app_state), content).with_code(ErrorCode::CommandFailed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn steps(steps: &[SyncStep]) -> Vec<(&str, &str)> {
+        steps.iter().map(|s| (s.branch.as_str(), s.parent.as_str())).collect()
+    }
+
+    #[test]
+    fn feature_and_its_task_branches_are_synced() {
+        let parents = HashMap::from([
+            ("1-task".to_string(), "feature/10".to_string()),
+            ("2-task".to_string(), "feature/10".to_string()),
+            ("3-task".to_string(), "feature/11".to_string()),
+        ]);
+
+        let plan = get_sync_steps(&parents, "feature/10", "master".to_string(), |_| true);
+
+        assert_eq!(steps(&plan), vec![("feature/10", "master"), ("1-task", "feature/10"), ("2-task", "feature/10")]);
+    }
+
+    #[test]
+    fn remote_only_task_branches_are_skipped() {
+        let parents = HashMap::from([
+            ("1-task".to_string(), "feature/10".to_string()),
+            ("2-task".to_string(), "feature/10".to_string()),
+        ]);
+
+        let plan = get_sync_steps(&parents, "feature/10", "origin/master".to_string(), |branch| branch != "1-task");
+
+        assert_eq!(steps(&plan), vec![("feature/10", "origin/master"), ("2-task", "feature/10")]);
+    }
+}
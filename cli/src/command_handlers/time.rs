@@ -0,0 +1,294 @@
+use std::collections::BTreeMap;
+use chrono::{Datelike, Duration, Local, NaiveDate, TimeZone, Utc};
+use serde::Serialize;
+use helpers::{t, load_timers, save_timers, AppError, EpicIid, ErrorCode, IssueIid, Printer, ProjectConfig};
+use managers::gitlab::structs::GlTimelog;
+use managers::tracker::TaskKey;
+use crate::structs::AppState;
+use serde_json::json;
+
+/// Списания короче минуты GitLab не принимает
+const MIN_SPENT_SECONDS: i64 = 60;
+
+/// Ключ отчета для задач без эпика. У настоящих эпиков iid начинаются с 1
+const NO_EPIC: EpicIid = EpicIid(0);
+
+#[derive(Serialize, Default)]
+struct EpicReport {
+    title: String,
+    seconds: i64,
+    issues: BTreeMap<IssueIid, IssueReport>,
+}
+
+#[derive(Serialize, Default)]
+struct IssueReport {
+    title: String,
+    web_url: String,
+    seconds: i64,
+}
+
+pub fn time_start_command(app_state: &AppState, task: &Option<String>) -> Result<(), AppError> {
+    let task = app_state.get_task_key(task)?;
+
+    // Время по задачам Jira списать некуда, таймер только потерял бы его при остановке
+    if let TaskKey::Jira(key) = &task {
+        return Err(AppError::new(ErrorCode::Unsupported, t!("time-jira-timer-unsupported", task = key)));
+    }
+
+    let mut timers = load_timers();
+
+    if let Some(started) = timers.get(&task.to_string()) {
+        Printer::print_warning(t!("time-timer-already-started", task = task, time = format_timestamp(*started)), None);
+        return Ok(());
+    }
+
+    let now = Utc::now().timestamp();
+
+    if app_state.plan(t!("dry-run-start-timer", task = task)) {
+        Printer::print_result(&json!({ "task": task, "started_at": now, "dry_run": true }));
+        return Ok(());
+    }
+
+    timers.insert(task.to_string(), now);
+    save_timers(&timers)?;
+
+    Printer::print_success(t!("time-timer-started", task = task, time = format_timestamp(now)), None);
+    Printer::print_result(&json!({ "task": task, "started_at": now }));
+
+    Ok(())
+}
+
+pub fn time_stop_command(app_state: &AppState, project_config: &ProjectConfig, task: &Option<String>) -> Result<(), AppError> {
+    let task = app_state.get_task_key(task)?;
+    let mut timers = load_timers();
+
+    let started = timers.get(&task.to_string()).copied().ok_or_else(|| AppError::not_found(t!("time-timer-not-started", task = task)))?;
+    let seconds = Utc::now().timestamp() - started;
+
+    if app_state.plan(t!("dry-run-stop-timer", task = task, duration = format_duration(seconds))) {
+        Printer::print_result(&json!({ "task": task, "spent": format_duration(seconds), "dry_run": true }));
+        return Ok(());
+    }
+
+    // Если списать не удалось, таймер остается и время не теряется
+    if seconds < MIN_SPENT_SECONDS {
+        Printer::print_warning(t!("time-too-short", task = task), None);
+    } else {
+        spend_time(app_state, project_config, &task, format_duration(seconds))?;
+    }
+
+    timers.remove(&task.to_string());
+    save_timers(&timers)?;
+
+    Ok(())
+}
+
+pub fn time_log_command(app_state: &AppState, project_config: &ProjectConfig, duration: &str, task: &Option<String>) -> Result<(), AppError> {
+    let task = app_state.get_task_key(task)?;
+
+    spend_time(app_state, project_config, &task, duration.to_string())
+}
+
+/// Отчет по задачам и эпикам за неделю из списаний GitLab
+pub fn time_report_command(app_state: &AppState, project_config: &ProjectConfig, weeks_ago: u32) -> Result<(), AppError> {
+    let today = Local::now().date_naive();
+    let start = today - Duration::days(today.weekday().num_days_from_monday() as i64) - Duration::weeks(weeks_ago as i64);
+    let end = start + Duration::weeks(1);
+
+    let user = app_state.gitlab_manager.get_current_user()?;
+    let timelogs = app_state.gitlab_manager.get_timelogs(
+        project_config.project_id,
+        user.username,
+        to_utc_boundary(start),
+        to_utc_boundary(end),
+    )?;
+
+    let report = build_report(timelogs);
+
+    if Printer::is_structured() {
+        Printer::print_result(&json!({ "start": start, "end": end, "epics": report }));
+        return Ok(());
+    }
+
+    print_report(&report, start, end);
+
+    Ok(())
+}
+
+fn spend_time(app_state: &AppState, project_config: &ProjectConfig, task: &TaskKey, duration: String) -> Result<(), AppError> {
+    match task {
+        TaskKey::Gitlab(iid) => {
+            if app_state.plan(t!("dry-run-spend-time", task = task, duration = duration)) {
+                Printer::print_result(&json!({ "task": task, "spent": duration, "dry_run": true }));
+                return Ok(());
+            }
+
+            let stats = app_state.gitlab_manager.add_spent_time(project_config.project_id, *iid, duration.to_string())?;
+            let total = stats.human_total_time_spent.to_owned().unwrap_or(format_duration(stats.total_time_spent as i64));
+
+            Printer::print_success(t!("time-logged", iid = iid, duration = duration, total = total), None);
+            Printer::print_result(&json!({ "task": task, "spent": duration, "total_time_spent": stats.total_time_spent }));
+        }
+        TaskKey::Jira(key) => {
+            return Err(AppError::new(ErrorCode::Unsupported, t!("time-jira-unsupported", task = key, duration = duration)));
+        }
+    }
+
+    Ok(())
+}
+
+/// Начало локального дня в UTC. GraphQL API понимает время без зоны как UTC, и неделя сдвинулась бы на смещение зоны
+fn to_utc_boundary(date: NaiveDate) -> String {
+    let midnight = date.and_hms_opt(0, 0, 0).unwrap_or_default();
+
+    // В дни перехода на летнее время полночи может не быть, берем ближайший момент
+    let local = Local.from_local_datetime(&midnight).earliest()
+        .unwrap_or_else(|| Local.from_utc_datetime(&midnight));
+
+    local.with_timezone(&Utc).to_rfc3339()
+}
+
+/// Эпики по iid (NO_EPIC - задачи без эпика), внутри задачи по iid
+fn build_report(timelogs: Vec<GlTimelog>) -> BTreeMap<EpicIid, EpicReport> {
+    let mut report: BTreeMap<EpicIid, EpicReport> = BTreeMap::new();
+
+    for timelog in timelogs {
+        let issue = match timelog.issue {
+            Some(issue) => issue,
+            None => continue,
+        };
+
+        let (epic_iid, epic_title) = match &issue.epic {
+            Some(epic) => (epic.iid.parse().unwrap_or(NO_EPIC), epic.title.to_string()),
+            None => (NO_EPIC, t!("time-no-epic")),
+        };
+
+        let epic = report.entry(epic_iid).or_insert_with(|| EpicReport { title: epic_title, ..Default::default() });
+        epic.seconds += timelog.time_spent;
+
+        let issue_report = epic.issues.entry(issue.iid.parse().unwrap_or(IssueIid(0))).or_insert_with(|| IssueReport {
+            title: issue.title.to_string(),
+            web_url: issue.web_url.to_string(),
+            seconds: 0,
+        });
+        issue_report.seconds += timelog.time_spent;
+    }
+
+    report
+}
+
+fn print_report(report: &BTreeMap<EpicIid, EpicReport>, start: NaiveDate, end: NaiveDate) {
+    Printer::print_info(t!("time-week", start = start, end = end - Duration::days(1)), None);
+
+    if report.is_empty() {
+        Printer::print_warning(t!("time-no-entries"), None);
+        return;
+    }
+
+    let mut total = 0;
+
+    for (epic_iid, epic) in report {
+        if *epic_iid == NO_EPIC {
+            Printer::print(format!("{}  {}", epic.title, format_duration(epic.seconds)), None);
+        } else {
+            Printer::print(format!("&{} {}  {}", epic_iid, epic.title, format_duration(epic.seconds)), None);
+        }
+
+        for (issue_iid, issue) in &epic.issues {
+            Printer::print(format!("    #{} {}  {}", issue_iid, issue.title, format_duration(issue.seconds)), None);
+        }
+
+        total += epic.seconds;
+    }
+
+    Printer::print_success(t!("time-total", total = format_duration(total)), None);
+}
+
+/// Длительность в формате GitLab: 1h30m. Округляется до минут
+fn format_duration(seconds: i64) -> String {
+    let minutes = (seconds + 30) / 60;
+    let (hours, minutes) = (minutes / 60, minutes % 60);
+
+    match (hours, minutes) {
+        (0, m) => format!("{}m", m),
+        (h, 0) => format!("{}h", h),
+        (h, m) => format!("{}h{}m", h, m),
+    }
+}
+
+fn format_timestamp(timestamp: i64) -> String {
+    match Local.timestamp_opt(timestamp, 0).single() {
+        Some(time) => time.format("%d.%m %H:%M").to_string(),
+        None => timestamp.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Timelike};
+    use managers::gitlab::structs::{GlTimelogEpic, GlTimelogIssue};
+
+    fn timelog(issue_iid: &str, epic_iid: Option<&str>, seconds: i64) -> GlTimelog {
+        GlTimelog {
+            spent_at: "2026-10-19T10:00:00Z".to_string(),
+            time_spent: seconds,
+            issue: Some(GlTimelogIssue {
+                iid: issue_iid.to_string(),
+                title: format!("Задача {}", issue_iid),
+                web_url: format!("https://gitlab.example.com/issues/{}", issue_iid),
+                epic: epic_iid.map(|iid| GlTimelogEpic { iid: iid.to_string(), title: format!("Эпик {}", iid) }),
+            }),
+        }
+    }
+
+    #[test]
+    fn duration_is_rounded_to_minutes() {
+        assert_eq!(format_duration(0), "0m");
+        assert_eq!(format_duration(29), "0m");
+        assert_eq!(format_duration(30), "1m");
+        assert_eq!(format_duration(59 * 60), "59m");
+        assert_eq!(format_duration(3600), "1h");
+        assert_eq!(format_duration(3600 + 29), "1h");
+        assert_eq!(format_duration(5400), "1h30m");
+        assert_eq!(format_duration(25 * 3600 + 60), "25h1m");
+    }
+
+    #[test]
+    fn report_groups_by_epic_and_issue() {
+        let report = build_report(vec![
+            timelog("1", Some("10"), 600),
+            timelog("1", Some("10"), 1200),
+            timelog("2", Some("10"), 300),
+            timelog("3", None, 60),
+        ]);
+
+        assert_eq!(report.len(), 2);
+
+        let epic = &report[&EpicIid(10)];
+        assert_eq!(epic.seconds, 2100);
+        assert_eq!(epic.issues[&IssueIid(1)].seconds, 1800);
+        assert_eq!(epic.issues[&IssueIid(2)].seconds, 300);
+
+        let no_epic = &report[&NO_EPIC];
+        assert_eq!(no_epic.title, t!("time-no-epic"));
+        assert_eq!(no_epic.issues[&IssueIid(3)].seconds, 60);
+    }
+
+    #[test]
+    fn report_skips_timelogs_without_issue() {
+        let mut log = timelog("1", None, 600);
+        log.issue = None;
+
+        assert!(build_report(vec![log]).is_empty());
+    }
+
+    #[test]
+    fn utc_boundary_is_local_midnight() {
+        let date = NaiveDate::from_ymd_opt(2026, 10, 19).unwrap();
+        let boundary = DateTime::parse_from_rfc3339(to_utc_boundary(date).as_str()).unwrap().with_timezone(&Local);
+
+        assert_eq!(boundary.date_naive(), date);
+        assert_eq!((boundary.hour(), boundary.minute()), (0, 0));
+    }
+}
@@ -0,0 +1,341 @@
+use std::io::IsTerminal;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::crossterm::execute;
+use ratatui::crossterm::terminal::{enable_raw_mode, EnterAlternateScreen};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, List, ListItem, ListState, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+use helpers::{t, AppError, ErrorCode, LogError, MrIid, Printer, ProjectConfig};
+use managers::gitlab::structs::{GlIssue, GlIssueState, GlIssuesFilter, GlMergeRequest, GlMergeRequestState, GlMergeRequestsFilter};
+use managers::tracker::TaskKey;
+use crate::command_handlers::{checkout_mr_command, checkout_task_command, create_mr_command};
+use crate::commands::CreateMrArgs;
+use crate::structs::AppState;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Panel {
+    Issues,
+    MyMrs,
+    Reviews,
+    Branches,
+}
+
+const PANELS: [Panel; 4] = [Panel::Issues, Panel::MyMrs, Panel::Reviews, Panel::Branches];
+
+/// Действие, которое выполняется вне TUI: его вывод должен остаться в терминале
+enum Action {
+    CheckoutTask(TaskKey),
+    CheckoutMr(MrIid),
+    CreateMr(String),
+}
+
+#[derive(Default)]
+struct Dashboard {
+    panel: usize,
+    issues: Vec<GlIssue>,
+    my_mrs: Vec<GlMergeRequest>,
+    reviews: Vec<GlMergeRequest>,
+    branches: Vec<String>,
+    current_branch: String,
+    states: [ListState; 4],
+    status: String,
+}
+
+pub fn ui_command(app_state: &AppState, project_config: &ProjectConfig) -> Result<(), AppError> {
+    if Printer::is_structured() || !std::io::stdout().is_terminal() {
+        return Err(AppError::new(ErrorCode::Unsupported, t!("ui-requires-terminal")));
+    }
+
+    let mut dashboard = Dashboard::default();
+    dashboard.refresh(app_state, project_config);
+
+    let mut terminal = ratatui::try_init().log_error()?;
+    let result = run_dashboard(&mut terminal, &mut dashboard, app_state, project_config);
+    ratatui::restore();
+
+    result
+}
+
+fn run_dashboard(terminal: &mut DefaultTerminal, dashboard: &mut Dashboard, app_state: &AppState, project_config: &ProjectConfig) -> Result<(), AppError> {
+    loop {
+        terminal.draw(|frame| dashboard.draw(frame)).log_error()?;
+
+        let Event::Key(key) = event::read().log_error()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        let action = match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            KeyCode::Tab | KeyCode::Right => {
+                dashboard.panel = (dashboard.panel + 1) % PANELS.len();
+                None
+            }
+            KeyCode::BackTab | KeyCode::Left => {
+                dashboard.panel = (dashboard.panel + PANELS.len() - 1) % PANELS.len();
+                None
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                dashboard.move_selection(1);
+                None
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                dashboard.move_selection(-1);
+                None
+            }
+            KeyCode::Char('r') => {
+                dashboard.status = t!("ui-refreshing");
+                terminal.draw(|frame| dashboard.draw(frame)).log_error()?;
+                dashboard.refresh(app_state, project_config);
+                None
+            }
+            KeyCode::Char('o') => {
+                dashboard.open_selected();
+                None
+            }
+            KeyCode::Enter | KeyCode::Char('c') => dashboard.get_checkout_action(),
+            KeyCode::Char('m') => dashboard.get_create_mr_action(),
+            _ => None,
+        };
+
+        if let Some(action) = action {
+            suspend(terminal, || run_action(app_state, project_config, action))?;
+            dashboard.refresh(app_state, project_config);
+        }
+    }
+}
+
+/// Выходит из TUI на время действия и возвращается после нажатия Enter
+fn suspend(terminal: &mut DefaultTerminal, action: impl FnOnce() -> Result<(), AppError>) -> Result<(), AppError> {
+    ratatui::restore();
+
+    if let Err(err) = action() {
+        Printer::print_error(err.message, None);
+    }
+
+    Printer::print(t!("ui-press-enter"), None);
+    let mut line = String::new();
+    let _ = std::io::stdin().read_line(&mut line);
+
+    enable_raw_mode().log_error()?;
+    execute!(std::io::stdout(), EnterAlternateScreen).log_error()?;
+    terminal.clear().log_error()?;
+
+    Ok(())
+}
+
+fn run_action(app_state: &AppState, project_config: &ProjectConfig, action: Action) -> Result<(), AppError> {
+    match action {
+        Action::CheckoutTask(task) => checkout_task_command(app_state, project_config, &task),
+        Action::CheckoutMr(iid) => checkout_mr_command(app_state, project_config, iid),
+        Action::CreateMr(branch) => create_mr_command(app_state, project_config, &CreateMrArgs {
+            source: Some(branch),
+            ..Default::default()
+        }),
+    }
+}
+
+impl Dashboard {
+    /// Загружает все списки. Ошибки показываются в строке состояния, чтобы не закрывать TUI
+    fn refresh(&mut self, app_state: &AppState, project_config: &ProjectConfig) {
+        self.status = match self.load(app_state, project_config) {
+            Ok(_) => String::new(),
+            Err(err) => err.message,
+        };
+
+        let lengths = [self.issues.len(), self.my_mrs.len(), self.reviews.len(), self.branches.len()];
+        for (state, length) in self.states.iter_mut().zip(lengths) {
+            let selected = state.selected().unwrap_or(0).min(length.saturating_sub(1));
+            state.select(if length == 0 { None } else { Some(selected) });
+        }
+    }
+
+    fn load(&mut self, app_state: &AppState, project_config: &ProjectConfig) -> Result<(), AppError> {
+        self.current_branch = app_state.git_manager.get_current_branch().unwrap_or_default();
+        self.branches = app_state.git_manager.get_branches(false)?.into_iter()
+            .filter(|b| TaskKey::from_branch(b).is_some())
+            .collect();
+
+        let current_user = app_state.gitlab_manager.get_current_user()?;
+
+        self.issues = app_state.gitlab_manager.get_issues(project_config.project_id, &GlIssuesFilter {
+            assignee_id: Some(current_user.id),
+            state: Some(GlIssueState::Opened),
+            ..Default::default()
+        })?;
+
+        let mr_project_id = project_config.get_mr_project_id();
+
+        // В списке MR нет пайплайна, он есть только у отдельного MR
+        self.my_mrs = app_state.gitlab_manager.get_mrs(mr_project_id, &GlMergeRequestsFilter {
+            author_id: Some(current_user.id),
+            state: Some(GlMergeRequestState::Opened),
+            ..Default::default()
+        })?.into_iter()
+            .map(|mr| app_state.gitlab_manager.get_mr(mr_project_id, mr.iid).unwrap_or(mr))
+            .collect();
+
+        self.reviews = app_state.gitlab_manager.get_mrs(mr_project_id, &GlMergeRequestsFilter {
+            reviewer_id: Some(current_user.id),
+            state: Some(GlMergeRequestState::Opened),
+            ..Default::default()
+        })?;
+
+        Ok(())
+    }
+
+    fn get_panel(&self) -> Panel {
+        PANELS[self.panel]
+    }
+
+    fn get_selected(&self) -> Option<usize> {
+        self.states[self.panel].selected()
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        let length = match self.get_panel() {
+            Panel::Issues => self.issues.len(),
+            Panel::MyMrs => self.my_mrs.len(),
+            Panel::Reviews => self.reviews.len(),
+            Panel::Branches => self.branches.len(),
+        };
+        if length == 0 {
+            return;
+        }
+
+        let selected = self.get_selected().unwrap_or(0) as isize;
+        self.states[self.panel].select(Some((selected + delta).rem_euclid(length as isize) as usize));
+    }
+
+    fn get_checkout_action(&mut self) -> Option<Action> {
+        let index = self.get_selected()?;
+
+        match self.get_panel() {
+            Panel::Issues => Some(Action::CheckoutTask(TaskKey::Gitlab(self.issues[index].iid))),
+            Panel::Branches => TaskKey::from_branch(self.branches[index].as_str()).map(Action::CheckoutTask),
+            Panel::MyMrs => Some(Action::CheckoutMr(self.my_mrs[index].iid)),
+            Panel::Reviews => Some(Action::CheckoutMr(self.reviews[index].iid)),
+        }
+    }
+
+    fn get_create_mr_action(&mut self) -> Option<Action> {
+        let index = self.get_selected()?;
+
+        match self.get_panel() {
+            Panel::Issues => Some(Action::CreateMr(TaskKey::Gitlab(self.issues[index].iid).get_branch_name())),
+            Panel::Branches => Some(Action::CreateMr(self.branches[index].to_string())),
+            Panel::MyMrs | Panel::Reviews => {
+                self.status = t!("ui-mr-exists");
+                None
+            }
+        }
+    }
+
+    fn open_selected(&mut self) {
+        let Some(index) = self.get_selected() else {
+            return;
+        };
+
+        let url = match self.get_panel() {
+            Panel::Issues => Some(self.issues[index].web_url.to_string()),
+            Panel::MyMrs => Some(self.my_mrs[index].web_url.to_string()),
+            Panel::Reviews => Some(self.reviews[index].web_url.to_string()),
+            // Для локальной ветки открываем задачу, если она есть среди назначенных
+            Panel::Branches => match TaskKey::from_branch(self.branches[index].as_str()) {
+                Some(TaskKey::Gitlab(iid)) => self.issues.iter().find(|i| i.iid == iid).map(|i| i.web_url.to_string()),
+                _ => None,
+            },
+        };
+
+        self.status = match url {
+            Some(url) => match open::that_detached(url.as_str()) {
+                Ok(_) => t!("ui-opened", url = url),
+                Err(err) => err.to_string(),
+            },
+            None => t!("ui-nothing-to-open"),
+        };
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [header, body, footer] = Layout::vertical([Constraint::Length(1), Constraint::Min(0), Constraint::Length(1)])
+            .areas(frame.area());
+        let [top, bottom] = Layout::vertical([Constraint::Percentage(50); 2]).areas(body);
+        let [issues, my_mrs] = Layout::horizontal([Constraint::Percentage(50); 2]).areas(top);
+        let [reviews, branches] = Layout::horizontal([Constraint::Percentage(50); 2]).areas(bottom);
+
+        frame.render_widget(Paragraph::new(t!("ui-title", branch = self.current_branch)).bold(), header);
+
+        let active = self.get_panel();
+        let [issues_state, my_mrs_state, reviews_state, branches_state] = &mut self.states;
+
+        let items: Vec<ListItem> = self.issues.iter().map(format_issue).collect();
+        draw_list(frame, issues, active == Panel::Issues, t!("ui-panel-issues"), items, issues_state);
+
+        let items: Vec<ListItem> = self.my_mrs.iter().map(|mr| format_mr(mr, true)).collect();
+        draw_list(frame, my_mrs, active == Panel::MyMrs, t!("ui-panel-my-mrs"), items, my_mrs_state);
+
+        let items: Vec<ListItem> = self.reviews.iter().map(|mr| format_mr(mr, false)).collect();
+        draw_list(frame, reviews, active == Panel::Reviews, t!("ui-panel-reviews"), items, reviews_state);
+
+        let items: Vec<ListItem> = self.branches.iter().map(|branch| {
+            let marker = if *branch == self.current_branch { "* " } else { "  " };
+            ListItem::new(format!("{}{}", marker, branch))
+        }).collect();
+        draw_list(frame, branches, active == Panel::Branches, t!("ui-panel-branches"), items, branches_state);
+
+        let footer_text = if self.status.is_empty() { t!("ui-help") } else { self.status.to_string() };
+        frame.render_widget(Paragraph::new(footer_text).dim(), footer);
+    }
+}
+
+fn draw_list(frame: &mut Frame, area: Rect, active: bool, title: String, items: Vec<ListItem>, state: &mut ListState) {
+    let border = if active { Style::new().fg(Color::Cyan) } else { Style::new() };
+    let highlight = if active { Style::new().add_modifier(Modifier::REVERSED) } else { Style::new().add_modifier(Modifier::BOLD) };
+    let title = format!(" {} ({}) ", title, items.len());
+
+    let list = List::new(items)
+        .block(Block::bordered().title(title).border_style(border))
+        .highlight_style(highlight);
+
+    frame.render_stateful_widget(list, area, state);
+}
+
+fn format_issue(issue: &GlIssue) -> ListItem<'_> {
+    let mut spans = vec![Span::raw(format!("#{} ", issue.iid)).bold(), Span::raw(issue.title.as_str())];
+
+    if !issue.labels.is_empty() {
+        spans.push(Span::raw(format!(" [{}]", issue.labels.join(", "))).dim());
+    }
+
+    ListItem::new(Line::from(spans))
+}
+
+/// MR с пайплайном (мои MR) или с автором (MR на ревью)
+fn format_mr(mr: &GlMergeRequest, with_pipeline: bool) -> ListItem<'_> {
+    let mut spans = vec![Span::raw(format!("!{} ", mr.iid)).bold()];
+
+    if with_pipeline {
+        let (status, color) = match &mr.head_pipeline {
+            Some(pipeline) => (pipeline.status.as_str(), match pipeline.status.as_str() {
+                "success" => Color::Green,
+                "failed" | "canceled" => Color::Red,
+                "running" | "pending" | "created" => Color::Yellow,
+                _ => Color::Gray,
+            }),
+            None => ("-", Color::Gray),
+        };
+        spans.push(Span::styled(format!("[{}] ", status), Style::new().fg(color)));
+    }
+
+    spans.push(Span::raw(mr.title.as_str()));
+
+    if !with_pipeline {
+        spans.push(Span::raw(format!(" ({})", mr.author.username)).dim());
+    }
+
+    ListItem::new(Line::from(spans))
+}
@@ -1,15 +1,22 @@
-use helpers::{save_app_config, AppConfig, Printer};
+use helpers::{t, plan_app_config_save, save_app_config, AppConfig, AppError, ErrorCode, Printer};
+use serde_json::json;
 
-pub fn update_host_command(app_config: &AppConfig, host: &String) -> Result<(), String> {
+pub fn update_host_command(app_config: &AppConfig, host: &String, dry_run: bool) -> Result<(), AppError> {
     let mut new_config = app_config.clone();
     new_config.gitlab_host = host.to_string();
 
+    if plan_app_config_save(dry_run) {
+        Printer::print_result(&json!({ "updated": "gitlab_host", "host": host, "dry_run": true }));
+        return Ok(());
+    }
+
     match save_app_config(new_config) {
         Ok(_) => {
-            Printer::print_success("Токен успешно обновлен!".to_string(), None);
+            Printer::print_success(t!("update-host-success"), None);
+            Printer::print_result(&json!({ "updated": "gitlab_host", "host": host }));
         }
         Err(err) => {
-            return Err(err)
+            return Err(AppError::new(ErrorCode::Config, err))
         }
     };
 
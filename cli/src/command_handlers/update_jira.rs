@@ -0,0 +1,30 @@
+use helpers::{t, plan_app_config_save, save_app_config, AppConfig, AppError, ErrorCode, JiraConfig, Printer};
+use serde_json::json;
+
+pub fn update_jira_command(app_config: &AppConfig, host: &String, token: &String, user: &Option<String>, epic_link_field: &Option<String>, dry_run: bool) -> Result<(), AppError> {
+    let mut new_config = app_config.clone();
+    new_config.jira = Some(JiraConfig {
+        host: host.to_string(),
+        user: user.to_owned().unwrap_or_default(),
+        token: token.to_string(),
+        epic_link_field: epic_link_field.to_owned(),
+    });
+
+    if plan_app_config_save(dry_run) {
+        Printer::print_result(&json!({ "updated": "jira", "host": host, "dry_run": true }));
+        return Ok(());
+    }
+
+    match save_app_config(new_config) {
+        Ok(_) => {
+            Printer::print_success(t!("update-jira-success"), None);
+            Printer::print_result(&json!({ "updated": "jira", "host": host }));
+        }
+        Err(err) => {
+            return Err(AppError::new(ErrorCode::Config, err))
+        }
+    };
+
+    Ok(())
+
+}
@@ -0,0 +1,24 @@
+use helpers::{t, plan_app_config_save, save_app_config, AppConfig, AppError, ErrorCode, Language, Printer};
+use serde_json::json;
+
+pub fn update_language_command(app_config: &AppConfig, language: Language, dry_run: bool) -> Result<(), AppError> {
+    let mut new_config = app_config.clone();
+    new_config.language = Some(language);
+
+    if plan_app_config_save(dry_run) {
+        Printer::print_result(&json!({ "updated": "language", "language": language, "dry_run": true }));
+        return Ok(());
+    }
+
+    match save_app_config(new_config) {
+        Ok(_) => {
+            Printer::print_success(t!("update-language-success"), None);
+            Printer::print_result(&json!({ "updated": "language", "language": language }));
+        }
+        Err(err) => {
+            return Err(AppError::new(ErrorCode::Config, err))
+        }
+    };
+
+    Ok(())
+}
@@ -1,15 +1,22 @@
-use helpers::{save_app_config, AppConfig, Printer};
+use helpers::{t, plan_app_config_save, save_app_config, AppConfig, AppError, ErrorCode, Printer};
+use serde_json::json;
 
-pub fn update_token_command(app_config: &AppConfig, token: &String) -> Result<(), String> {
+pub fn update_token_command(app_config: &AppConfig, token: &String, dry_run: bool) -> Result<(), AppError> {
     let mut new_config = app_config.clone();
     new_config.gitlab_token = token.to_string();
 
+    if plan_app_config_save(dry_run) {
+        Printer::print_result(&json!({ "updated": "gitlab_token", "dry_run": true }));
+        return Ok(());
+    }
+
     match save_app_config(new_config) {
         Ok(_) => {
-            Printer::print_success("Токен успешно обновлен!".to_string(), None);
+            Printer::print_success(t!("update-token-success"), None);
+            Printer::print_result(&json!({ "updated": "gitlab_token" }));
         }
         Err(err) => {
-            return Err(err)
+            return Err(AppError::new(ErrorCode::Config, err))
         }
     };
 
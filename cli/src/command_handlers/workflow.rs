@@ -0,0 +1,75 @@
+use helpers::{t, AppError, Printer, ProjectConfig, WorkflowStep};
+use managers::gitlab::structs::GlIssueUpdate;
+use managers::tracker::TaskKey;
+use crate::structs::AppState;
+
+#[derive(Debug, Clone, Copy)]
+pub enum WorkflowStage {
+    InProgress,
+    Review,
+    Done,
+}
+
+pub fn apply_workflow_step(app_state: &AppState, project_config: &ProjectConfig, task: &TaskKey, stage: WorkflowStage) -> Result<(), AppError> {
+    let step = match stage {
+        WorkflowStage::InProgress => project_config.workflow.in_progress.clone(),
+        WorkflowStage::Review => project_config.workflow.review.clone(),
+        WorkflowStage::Done => Some(project_config.workflow.done.clone().unwrap_or(WorkflowStep { close: true, ..Default::default() })),
+    };
+
+    let step = match step {
+        Some(s) => s,
+        None => return Ok(()),
+    };
+
+    let issue_iid = match task {
+        TaskKey::Gitlab(iid) => *iid,
+        TaskKey::Jira(key) => {
+            Printer::print_warning(t!("workflow-gitlab-only", task = key), None);
+            return Ok(());
+        }
+    };
+
+    if app_state.dry_run {
+        let mut changes: Vec<String> = step.add_labels.iter().map(|l| format!("+{}", l)).collect();
+        changes.extend(step.remove_labels.iter().map(|l| format!("-{}", l)));
+        if step.assign_me {
+            changes.push(t!("dry-run-assign-me"));
+        }
+        if step.close {
+            changes.push(t!("dry-run-close"));
+        }
+
+        Printer::print_plan(t!("dry-run-update-issue", iid = issue_iid, changes = changes.join(", ")), None);
+        return Ok(());
+    }
+
+    let assignee_ids = if step.assign_me {
+        let current_user = app_state.gitlab_manager.get_current_user()?;
+        let issue = app_state.gitlab_manager.get_issue(issue_iid, project_config.project_id)?;
+
+        let mut ids: Vec<u64> = issue.assignees.iter().map(|a| a.id).collect();
+        if !ids.contains(&current_user.id) {
+            ids.push(current_user.id);
+        }
+        Some(ids)
+    } else {
+        None
+    };
+
+    let issue = app_state.gitlab_manager.update_issue(
+        project_config.project_id,
+        issue_iid,
+        GlIssueUpdate {
+            add_labels: step.add_labels,
+            remove_labels: step.remove_labels,
+            assignee_ids,
+            close: step.close,
+        },
+    )?;
+
+    let labels = if issue.labels.is_empty() { t!("workflow-no-labels") } else { issue.labels.join(", ") };
+    Printer::print_info(t!("workflow-issue-state", iid = issue.iid, state = issue.state, labels = labels), None);
+
+    Ok(())
+}
@@ -1,45 +1,603 @@
-use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+use clap_complete::{ArgValueCandidates, Shell};
+use serde::Serialize;
+use helpers::{t, EpicIid, MrIid, ProjectId};
+use crate::command_handlers::completions::{complete_features, complete_remote_branches, complete_tasks};
 
 #[derive(Parser, Debug)]
+#[allow(clippy::upper_case_acronyms)]
 pub struct CLI {
     #[command(subcommand)]
     pub command: Option<Commands>,
+
+    #[arg(help = t!("arg-output"), long, global = true, value_enum, default_value_t = OutputMode::Text)]
+    pub output: OutputMode,
+
+    #[arg(help = t!("arg-quiet"), short, long, global = true, conflicts_with = "verbose")]
+    pub quiet: bool,
+
+    #[arg(help = t!("arg-verbose"), short, long, global = true, action = ArgAction::Count)]
+    pub verbose: u8,
+
+    #[arg(help = t!("arg-log-level"), long, global = true, value_enum)]
+    pub log_level: Option<LogLevel>,
+
+    #[arg(help = t!("arg-dry-run"), long, global = true)]
+    pub dry_run: bool,
 }
 
 #[derive(Subcommand, Debug)]
 pub enum Commands {
-    #[clap(about = "Инициализировать проект")]
+    #[clap(about = t!("cmd-init"))]
     Init,
 
-    #[clap(subcommand, about = "Глобальная конфигурация (alias: gc)", alias = "gc")]
+    #[clap(subcommand, about = t!("cmd-global-config"), visible_alias = "gc")]
     GlobalConfig(GlobalConfigCommands),
 
-    #[clap(about = "Переключиться на ветку задачи (alias: cot)", alias = "cot")]
-    CheckoutTask { task_iid: u64 },
+    #[clap(about = t!("cmd-checkout-task"), visible_alias = "cot")]
+    CheckoutTask {
+        #[arg(help = t!("arg-task-key"), add = ArgValueCandidates::new(complete_tasks))]
+        task: String,
+    },
+
+    #[clap(about = t!("cmd-checkout-feature"), visible_alias = "cof")]
+    CheckoutFeature {
+        #[arg(help = t!("arg-feature-key"), add = ArgValueCandidates::new(complete_features))]
+        feature: String,
+    },
+
+    #[clap(about = t!("cmd-tasks"), visible_alias = "ts")]
+    Tasks {
+        #[arg(help = t!("arg-milestone"), short, long)]
+        milestone: Option<String>,
+
+        #[arg(help = t!("arg-label"), short, long = "label")]
+        labels: Vec<String>,
+
+        #[arg(help = t!("arg-tasks-state"), short, long, value_enum, default_value_t = TaskState::Opened)]
+        state: TaskState,
+
+        #[arg(help = t!("arg-epic-iid"), short, long)]
+        epic: Option<EpicIid>,
 
-    #[clap(about = "Переключиться на ветку фичи (alias: cof)", alias = "cof")]
-    CheckoutFeature { feature_iid: u16 },
+        // То же, что --output json
+        #[arg(long, hide = true)]
+        json: bool,
+    },
+
+    #[clap(about = t!("cmd-done"))]
+    Done {
+        #[arg(help = t!("arg-task-from-branch"))]
+        task: Option<String>,
+    },
 
-    #[clap(about = "Создать MR (alias: mr)", alias = "mr")]
+    #[clap(subcommand, about = t!("cmd-task"), visible_alias = "tk")]
+    Task(TaskCommands),
+
+    #[clap(subcommand, about = t!("cmd-epic"), visible_alias = "ep")]
+    Epic(EpicCommands),
+
+    #[clap(about = t!("cmd-merge-request"), visible_alias = "mr", args_conflicts_with_subcommands = true)]
     MergeRequest {
-        /// Исходная ветка
-        #[arg(short, long)]
-        source: Option<String>,
+        #[command(subcommand)]
+        command: Option<MrCommands>,
 
-        /// Целевая ветка
-        #[arg(short, long)]
-        target: Option<String>,
+        #[command(flatten)]
+        create: CreateMrArgs,
+    },
+
+    #[clap(subcommand, about = t!("cmd-stack"), visible_alias = "st")]
+    Stack(StackCommands),
+
+    #[clap(about = t!("cmd-sync"))]
+    Sync {
+        #[arg(help = t!("arg-sync-strategy"), short, long, value_enum)]
+        strategy: Option<SyncMode>,
 
-        /// Добавить ревьюверов в MR
-        #[arg(short, long)]
-        review: bool
+        #[arg(help = t!("arg-sync-continue"), long = "continue", conflicts_with_all = ["abort", "strategy"])]
+        continue_sync: bool,
+
+        #[arg(help = t!("arg-sync-abort"), long, conflicts_with = "strategy")]
+        abort: bool,
     },
+
+    #[clap(about = t!("cmd-cleanup"))]
+    Cleanup {
+        #[arg(help = t!("arg-cleanup-local"), short, long)]
+        local: bool,
+
+        #[arg(help = t!("arg-cleanup-yes"), short, long)]
+        yes: bool,
+    },
+
+    #[clap(subcommand, about = t!("cmd-time"), visible_alias = "tm")]
+    Time(TimeCommands),
+
+    #[clap(about = t!("cmd-commit"), visible_alias = "ci")]
+    Commit {
+        #[arg(help = t!("arg-commit-message"), short, long)]
+        message: Option<String>,
+
+        #[arg(help = t!("arg-commit-type"), short = 't', long = "type")]
+        commit_type: Option<String>,
+
+        #[arg(help = t!("arg-commit-all"), short, long)]
+        all: bool,
+    },
+
+    #[clap(subcommand, about = t!("cmd-hooks"))]
+    Hooks(HooksCommands),
+
+    #[clap(about = t!("cmd-logs"))]
+    Logs {
+        #[arg(help = t!("arg-logs-lines"), short = 'n', long, default_value_t = 50)]
+        lines: usize,
+
+        #[arg(help = t!("arg-logs-follow"), short, long)]
+        follow: bool,
+
+        #[arg(help = t!("arg-logs-path"), long, conflicts_with = "follow")]
+        path: bool,
+    },
+
+    #[clap(about = t!("cmd-completions"))]
+    Completions {
+        #[arg(help = t!("arg-completions-shell"), value_enum, required_unless_present = "refresh")]
+        shell: Option<Shell>,
+
+        #[arg(help = t!("arg-completions-refresh"), long, conflicts_with = "shell")]
+        refresh: bool,
+    },
+
+    #[clap(about = t!("cmd-open"))]
+    Open {
+        #[arg(help = t!("arg-open-target"), value_enum, default_value_t = OpenTarget::Issue)]
+        target: OpenTarget,
+
+        #[arg(help = t!("arg-open-print"), short, long)]
+        print: bool,
+
+        #[arg(help = t!("arg-open-copy"), short, long)]
+        copy: bool,
+    },
+
+    #[clap(about = t!("cmd-ui"))]
+    Ui,
+
+    #[clap(about = t!("cmd-help-gen"))]
+    HelpGen {
+        #[arg(help = t!("arg-help-gen-out"), short, long, default_value = "docs")]
+        out: PathBuf,
+    },
+
+    #[clap(subcommand, hide = true)]
+    Hook(HookCommands),
+
+    // Фоновый владелец буфера обмена для `open --copy`, текст приходит в stdin
+    #[clap(hide = true)]
+    HoldClipboard,
+}
+
+#[derive(Args, Debug, Default)]
+pub struct CreateMrArgs {
+    #[arg(help = t!("arg-mr-create-source"), short, long)]
+    pub source: Option<String>,
+
+    #[arg(help = t!("arg-mr-create-target"), short, long, add = ArgValueCandidates::new(complete_remote_branches))]
+    pub target: Option<String>,
+
+    #[arg(help = t!("arg-mr-create-review"), short, long)]
+    pub review: bool,
+
+    #[arg(help = t!("arg-mr-create-template"), short = 'T', long, num_args = 0..=1, default_missing_value = "")]
+    pub template: Option<String>,
+
+    #[arg(help = t!("arg-mr-create-edit"), short, long)]
+    pub edit: bool,
+
+    #[arg(help = t!("arg-mr-create-draft"), short, long, overrides_with = "no_draft")]
+    pub draft: bool,
+
+    #[arg(help = t!("arg-mr-create-no-draft"), long, overrides_with = "draft")]
+    pub no_draft: bool,
+
+    #[arg(help = t!("arg-mr-create-labels"), short, long = "label")]
+    pub labels: Vec<String>,
+
+    #[arg(help = t!("arg-mr-create-copy-labels"), long)]
+    pub copy_labels: bool,
+
+    #[arg(help = t!("arg-milestone"), short, long)]
+    pub milestone: Option<String>,
+
+    #[arg(help = t!("arg-mr-create-squash"), long, overrides_with = "no_squash")]
+    pub squash: bool,
+
+    #[arg(help = t!("arg-mr-create-no-squash"), long, overrides_with = "squash")]
+    pub no_squash: bool,
+
+    #[arg(help = t!("arg-mr-create-remove-source-branch"), long, overrides_with = "keep_source_branch")]
+    pub remove_source_branch: bool,
+
+    #[arg(help = t!("arg-mr-create-keep-source-branch"), long, overrides_with = "remove_source_branch")]
+    pub keep_source_branch: bool,
+
+    #[arg(help = t!("arg-mr-create-allow-collaboration"), long)]
+    pub allow_collaboration: bool,
+
+    #[arg(help = t!("arg-mr-create-target-project"), long)]
+    pub target_project: Option<ProjectId>,
 }
 
 #[derive(Subcommand, Debug)]
+#[allow(clippy::enum_variant_names)]
 pub enum GlobalConfigCommands {
-    #[clap(about = "Установить токен (alias: st)", alias = "st", hide = false)]
+    #[clap(about = t!("cmd-gc-set-token"), visible_alias = "st", hide = false)]
     SetToken { token: String },
-    #[clap(about = "Установить gitlab хост (alias: sh)", alias = "sh", hide = false)]
+    #[clap(about = t!("cmd-gc-set-host"), visible_alias = "sh", hide = false)]
     SetHost { host: String },
-}
\ No newline at end of file
+    #[clap(about = t!("cmd-gc-set-jira"), visible_alias = "sj", hide = false)]
+    SetJira {
+        #[arg(help = t!("arg-gc-set-jira-host"))]
+        host: String,
+
+        #[arg(help = t!("arg-gc-set-jira-token"))]
+        token: String,
+
+        #[arg(help = t!("arg-gc-set-jira-user"), short, long)]
+        user: Option<String>,
+
+        #[arg(help = t!("arg-gc-set-jira-epic-link-field"), short, long)]
+        epic_link_field: Option<String>,
+    },
+    #[clap(about = t!("cmd-gc-set-language"), visible_alias = "sl", hide = false)]
+    SetLanguage {
+        #[arg(help = t!("arg-gc-set-language-language"), value_enum)]
+        language: LanguageMode,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum MrCommands {
+    #[clap(about = t!("cmd-mr-list"), visible_alias = "ls")]
+    List {
+        #[arg(help = t!("arg-mr-list-state"), short, long, value_enum, default_value_t = MrState::Opened)]
+        state: MrState,
+
+        #[arg(help = t!("arg-mr-list-all"), short, long)]
+        all: bool,
+    },
+
+    #[clap(about = t!("cmd-mr-view"))]
+    View {
+        #[arg(help = t!("arg-mr-iid"))]
+        iid: Option<MrIid>,
+    },
+
+    #[clap(about = t!("cmd-mr-approve"))]
+    Approve {
+        #[arg(help = t!("arg-mr-iid"))]
+        iid: Option<MrIid>,
+    },
+
+    #[clap(about = t!("cmd-mr-merge"))]
+    Merge {
+        #[arg(help = t!("arg-mr-iid"))]
+        iid: Option<MrIid>,
+
+        #[arg(help = t!("arg-mr-merge-when-pipeline-succeeds"), short, long)]
+        when_pipeline_succeeds: bool,
+
+        #[arg(help = t!("arg-mr-merge-squash"), long)]
+        squash: bool,
+
+        #[arg(help = t!("arg-mr-merge-remove-source-branch"), long)]
+        remove_source_branch: bool,
+    },
+
+    #[clap(about = t!("cmd-mr-rebase"))]
+    Rebase {
+        #[arg(help = t!("arg-mr-iid"))]
+        iid: Option<MrIid>,
+    },
+
+    #[clap(about = t!("cmd-mr-close"))]
+    Close {
+        #[arg(help = t!("arg-mr-iid"))]
+        iid: Option<MrIid>,
+    },
+
+    #[clap(about = t!("cmd-mr-reopen"))]
+    Reopen {
+        #[arg(help = t!("arg-mr-iid"))]
+        iid: Option<MrIid>,
+    },
+
+    #[clap(about = t!("cmd-mr-comments"))]
+    Comments {
+        #[arg(help = t!("arg-mr-iid"))]
+        iid: Option<MrIid>,
+
+        #[arg(help = t!("arg-mr-comments-all"), short, long)]
+        all: bool,
+
+        // То же, что --output json
+        #[arg(long, hide = true)]
+        json: bool,
+    },
+
+    #[clap(about = t!("cmd-mr-reply"))]
+    Reply {
+        #[arg(help = t!("arg-discussion"))]
+        discussion: String,
+
+        #[arg(help = t!("arg-mr-reply-body"))]
+        body: Option<String>,
+
+        #[arg(help = t!("arg-mr-iid"), short, long)]
+        iid: Option<MrIid>,
+
+        #[arg(help = t!("arg-mr-reply-resolve"), short, long)]
+        resolve: bool,
+    },
+
+    #[clap(about = t!("cmd-mr-resolve"))]
+    Resolve {
+        #[arg(help = t!("arg-discussion"))]
+        discussion: String,
+
+        #[arg(help = t!("arg-mr-iid"), short, long)]
+        iid: Option<MrIid>,
+
+        #[arg(help = t!("arg-mr-resolve-unresolve"), short, long)]
+        unresolve: bool,
+    },
+
+    #[clap(about = t!("cmd-mr-note"))]
+    Note {
+        #[arg(help = t!("arg-mr-note-body"))]
+        body: Option<String>,
+
+        #[arg(help = t!("arg-mr-iid"), short, long)]
+        iid: Option<MrIid>,
+    },
+
+    #[clap(about = t!("cmd-mr-checkout"))]
+    Checkout {
+        #[arg(help = t!("arg-mr-checkout-iid"))]
+        iid: MrIid,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum StackCommands {
+    #[clap(about = t!("cmd-stack-show"))]
+    Show,
+
+    #[clap(about = t!("cmd-stack-new"))]
+    New {
+        #[arg(help = t!("arg-task-key"))]
+        task: String,
+    },
+
+    #[clap(about = t!("cmd-stack-set-parent"))]
+    SetParent {
+        #[arg(help = t!("arg-stack-set-parent-parent"))]
+        parent: String,
+
+        #[arg(help = t!("arg-stack-set-parent-branch"), short, long)]
+        branch: Option<String>,
+    },
+
+    #[clap(about = t!("cmd-stack-rebase"))]
+    Rebase {
+        #[arg(help = t!("arg-stack-rebase-push"), short, long)]
+        push: bool,
+    },
+
+    #[clap(about = t!("cmd-stack-mr"))]
+    Mr {
+        #[arg(help = t!("arg-stack-mr-draft"), short, long)]
+        draft: bool,
+    },
+
+    #[clap(about = t!("cmd-stack-retarget"))]
+    Retarget,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum TimeCommands {
+    #[clap(about = t!("cmd-time-start"))]
+    Start {
+        #[arg(help = t!("arg-task-from-branch"))]
+        task: Option<String>,
+    },
+
+    #[clap(about = t!("cmd-time-stop"))]
+    Stop {
+        #[arg(help = t!("arg-task-from-branch"))]
+        task: Option<String>,
+    },
+
+    #[clap(about = t!("cmd-time-log"))]
+    Log {
+        #[arg(help = t!("arg-time-log-duration"))]
+        duration: String,
+
+        #[arg(help = t!("arg-task-from-branch"))]
+        task: Option<String>,
+    },
+
+    #[clap(about = t!("cmd-time-report"))]
+    Report {
+        #[arg(help = t!("arg-time-report-weeks-ago"), short, long, default_value_t = 0)]
+        weeks_ago: u32,
+
+        // То же, что --output json
+        #[arg(long, hide = true)]
+        json: bool,
+    },
+}
+
+/// Точки входа git хуков, установленных gw
+#[derive(Subcommand, Debug)]
+pub enum HookCommands {
+    PrepareCommitMsg {
+        file: PathBuf,
+        source: Option<String>,
+        sha: Option<String>,
+    },
+
+    CommitMsg {
+        file: PathBuf,
+    },
+
+    PrePush {
+        remote: String,
+        url: Option<String>,
+    },
+
+    PostCheckout {
+        previous: String,
+        new: String,
+        /// 1 - переключение ветки, 0 - файлов
+        flag: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum HooksCommands {
+    #[clap(about = t!("cmd-hooks-install"))]
+    Install,
+
+    #[clap(about = t!("cmd-hooks-uninstall"))]
+    Uninstall,
+
+    #[clap(about = t!("cmd-hooks-status"))]
+    Status,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum TaskCommands {
+    #[clap(about = t!("cmd-task-new"))]
+    New {
+        #[arg(help = t!("arg-task-new-title"))]
+        title: String,
+
+        #[arg(help = t!("arg-task-new-description"), short, long)]
+        description: Option<String>,
+
+        #[arg(help = t!("arg-label"), short, long = "label")]
+        labels: Vec<String>,
+
+        #[arg(help = t!("arg-milestone"), short, long)]
+        milestone: Option<String>,
+
+        #[arg(help = t!("arg-task-new-weight"), short, long)]
+        weight: Option<u64>,
+
+        #[arg(help = t!("arg-task-new-epic"), short, long)]
+        epic: Option<EpicIid>,
+
+        #[arg(help = t!("arg-task-new-checkout"), short, long)]
+        checkout: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum EpicCommands {
+    #[clap(about = t!("cmd-epic-tree"))]
+    Tree {
+        #[arg(help = t!("arg-epic-iid"))]
+        iid: EpicIid,
+
+        #[arg(help = t!("arg-epic-tree-mine"), long)]
+        mine: bool,
+    },
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum TaskState {
+    Opened,
+    Closed,
+    All,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OpenTarget {
+    Issue,
+    Epic,
+    Mr,
+    Pipeline,
+    Project,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum MrState {
+    Opened,
+    Closed,
+    Merged,
+    All,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum SyncMode {
+    Merge,
+    Rebase,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum LanguageMode {
+    Ru,
+    En,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum LogLevel {
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum OutputMode {
+    Text,
+    Json,
+    Ndjson,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_mr_args(args: &[&str]) -> CreateMrArgs {
+        let cli = CLI::try_parse_from([&["gw", "mr"], args].concat()).unwrap();
+        match cli.command {
+            Some(Commands::MergeRequest { command: None, create }) => create,
+            other => panic!("unexpected command: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn last_squash_flag_wins() {
+        let args = parse_mr_args(&["--squash", "--no-squash"]);
+        assert!(!args.squash && args.no_squash);
+
+        let args = parse_mr_args(&["--no-squash", "--squash"]);
+        assert!(args.squash && !args.no_squash);
+    }
+
+    #[test]
+    fn last_draft_flag_wins() {
+        let args = parse_mr_args(&["--draft", "--no-draft"]);
+        assert!(!args.draft && args.no_draft);
+
+        let args = parse_mr_args(&["--no-draft", "-d"]);
+        assert!(args.draft && !args.no_draft);
+    }
+}
@@ -1,52 +1,90 @@
-use std::fs::File;
 use std::path::PathBuf;
-use clap::Parser;
+use clap::{CommandFactory, Parser};
+use clap::error::ErrorKind;
+use clap_complete::CompleteEnv;
 use log::{error, LevelFilter};
-use simplelog::{CombinedLogger, Config, WriteLogger};
-use crate::commands::{Commands, GlobalConfigCommands, CLI};
-use helpers::{load_app_config, Printer, load_project_config, {AppConfig, ProjectConfig}};
-use managers::{GitManager, GitlabManager};
+use simplelog::{ColorChoice, CombinedLogger, ConfigBuilder, SharedLogger, TermLogger, TerminalMode, WriteLogger};
+use crate::commands::{Commands, EpicCommands, GlobalConfigCommands, HookCommands, HooksCommands, LanguageMode, LogLevel, MrCommands, OutputMode, StackCommands, SyncMode, TimeCommands, TaskCommands, CLI};
+use helpers::{t, init_language, load_app_config, open_log_file, Printer, load_project_config, {AppConfig, ErrorCode, Language, OutputFormat, ProjectConfig, SyncStrategy, Verbosity}};
+use managers::{GitManager, GitlabManager, JiraManager};
+use managers::tracker::{FeatureKey, TaskKey};
 use structs::{AppState};
-use crate::command_handlers::{checkout_feature_command, checkout_task_command, create_mr_command, init_command, update_host_command, update_token_command};
+use crate::command_handlers::create_task::NewTaskArgs;
+use crate::command_handlers::{approve_mr_command, checkout_mr_command, list_mrs_command, merge_mr_command, rebase_mr_command, set_mr_state_command, view_mr_command};
+use crate::command_handlers::cleanup_command;
+use crate::command_handlers::logs_command;
+use crate::command_handlers::{completions_command, refresh_completions_command};
+use crate::command_handlers::help_gen_command;
+use crate::command_handlers::ui_command;
+use crate::command_handlers::{hold_clipboard_command, open_command};
+use crate::command_handlers::{commit_command, commit_msg_hook, prepare_commit_msg_hook};
+use crate::command_handlers::{hooks_install_command, hooks_status_command, hooks_uninstall_command, post_checkout_hook, pre_push_hook};
+use crate::command_handlers::{time_log_command, time_report_command, time_start_command, time_stop_command};
+use crate::command_handlers::{sync_abort_command, sync_command, sync_continue_command};
+use crate::command_handlers::{stack_mr_command, stack_new_command, stack_rebase_command, stack_retarget_command, stack_set_parent_command, stack_show_command};
+use crate::command_handlers::{mr_comments_command, note_command, reply_command, resolve_command};
+use crate::command_handlers::{checkout_feature_command, checkout_task_command, create_mr_command, create_task_command, done_command, epic_tree_command, init_command, list_tasks_command, update_host_command, update_jira_command, update_language_command, update_token_command};
 
 mod structs;
 mod commands;
 mod command_handlers;
 
-fn get_app_state(app_config: AppConfig, current_dir: PathBuf) -> Option<AppState> {
-    let gitlab_manager = match GitlabManager::new((&app_config).gitlab_token.to_string(), (&app_config).gitlab_host.to_string()) {
+fn get_app_state(app_config: AppConfig, current_dir: PathBuf, dry_run: bool) -> Option<AppState> {
+    let gitlab_manager = match GitlabManager::new(app_config.gitlab_token.to_string(), app_config.gitlab_host.to_string()) {
         Ok(gm) => gm,
         Err(err) => {
-            Printer::print_error(err, Some("GitLab".to_string()));
+            Printer::print_failure(err.code, err.message);
             return None;
         }
     };
 
-    let git_manager = match GitManager::new(&current_dir) {
+    let git_manager = match GitManager::new(&current_dir, dry_run) {
         Ok(gm) => gm,
         Err(err) => {
-            Printer::print_error(err, Some("Git".to_string()));
+            Printer::print_failure(err.code, err.message);
             return None;
         }
     };
 
+    let jira_manager = match &app_config.jira {
+        None => None,
+        Some(jira_config) => match JiraManager::new(jira_config.clone()) {
+            Ok(jm) => Some(jm),
+            Err(err) => {
+                Printer::print_failure(err.code, err.message);
+                return None;
+            }
+        }
+    };
+
     Some(AppState {
         app_config,
         git_manager,
         gitlab_manager,
+        jira_manager,
         path: current_dir,
+        dry_run,
     })
 }
 
 
-fn process_core_commands(parsed_command: &Commands, config: &AppConfig) -> Option<()> {
+fn process_core_commands(parsed_command: &Commands, config: &AppConfig, dry_run: bool) -> Option<()> {
     // Команды, которым не нужны менеджеры
     let res = match parsed_command {
         Commands::GlobalConfig(gc_command) =>
             match gc_command {
-                GlobalConfigCommands::SetToken { token } => update_token_command(config, token),
-                GlobalConfigCommands::SetHost { host } => update_host_command(config, host),
+                GlobalConfigCommands::SetToken { token } => update_token_command(config, token, dry_run),
+                GlobalConfigCommands::SetHost { host } => update_host_command(config, host, dry_run),
+                GlobalConfigCommands::SetJira { host, token, user, epic_link_field } => update_jira_command(config, host, token, user, epic_link_field, dry_run),
+                GlobalConfigCommands::SetLanguage { language } => update_language_command(config, match language {
+                    LanguageMode::Ru => Language::Ru,
+                    LanguageMode::En => Language::En,
+                }, dry_run),
             },
+        Commands::Logs { lines, follow, path } => logs_command(*lines, *follow, *path),
+        Commands::Completions { shell: Some(shell), .. } => completions_command(*shell),
+        Commands::HelpGen { out } => help_gen_command(out, dry_run),
+        Commands::HoldClipboard => hold_clipboard_command(),
         _ => {
             return None
         }
@@ -55,7 +93,7 @@ fn process_core_commands(parsed_command: &Commands, config: &AppConfig) -> Optio
     match res {
         Ok(_) => {}
         Err(err) => {
-            Printer::print_error(err, None);
+            Printer::print_failure(err.code, err.message);
         }
     };
 
@@ -63,7 +101,7 @@ fn process_core_commands(parsed_command: &Commands, config: &AppConfig) -> Optio
 }
 
 
-fn process_base_commands(parsed_command: &Commands, config: &AppConfig, app_state: &AppState) -> Option<()> {
+fn process_base_commands(parsed_command: &Commands, app_state: &AppState) -> Option<()> {
     // Команды, которым не нужен конфиг проекта
     let res = match parsed_command {
         Commands::Init => init_command(app_state),
@@ -75,7 +113,7 @@ fn process_base_commands(parsed_command: &Commands, config: &AppConfig, app_stat
     match res {
         Ok(_) => {}
         Err(err) => {
-            Printer::print_error(err, None);
+            Printer::print_failure(err.code, err.message);
         }
     };
 
@@ -85,9 +123,86 @@ fn process_base_commands(parsed_command: &Commands, config: &AppConfig, app_stat
 fn process_commands(parsed_command: &Commands, project_config: &ProjectConfig, app_state: &AppState) -> Option<()> {
     // Команды, которым нужны все менеджеры и конфиги
     let res = match parsed_command {
-        Commands::CheckoutFeature { feature_iid: feature } => checkout_feature_command(app_state, project_config, *feature),
-        Commands::CheckoutTask { task_iid: task } => checkout_task_command(app_state, project_config, *task),
-        Commands::MergeRequest { source, target, review } => create_mr_command(app_state, project_config, source.to_owned(), target.to_owned()),
+        Commands::CheckoutFeature { feature } => FeatureKey::parse(feature).and_then(|feature| checkout_feature_command(app_state, project_config, &feature)),
+        Commands::CheckoutTask { task } => TaskKey::parse(task).and_then(|task| checkout_task_command(app_state, project_config, &task)),
+        Commands::Tasks { milestone, labels, state, epic, .. } => list_tasks_command(app_state, project_config, milestone, labels, *state, *epic),
+        Commands::Done { task } => done_command(app_state, project_config, task),
+        Commands::Task(task_command) =>
+            match task_command {
+                TaskCommands::New { title, description, labels, milestone, weight, epic, checkout } => create_task_command(
+                    app_state,
+                    project_config,
+                    NewTaskArgs {
+                        title: title.to_string(),
+                        description: description.to_owned(),
+                        labels: labels.to_vec(),
+                        milestone: milestone.to_owned(),
+                        weight: *weight,
+                        epic: *epic,
+                        checkout: *checkout,
+                    },
+                ),
+            },
+        Commands::Epic(epic_command) =>
+            match epic_command {
+                EpicCommands::Tree { iid, mine } => epic_tree_command(app_state, project_config, *iid, *mine),
+            },
+        Commands::Stack(stack_command) =>
+            match stack_command {
+                StackCommands::Show => stack_show_command(app_state, project_config),
+                StackCommands::New { task } => TaskKey::parse(task).and_then(|task| stack_new_command(app_state, project_config, &task)),
+                StackCommands::SetParent { parent, branch } => stack_set_parent_command(app_state, parent, branch),
+                StackCommands::Rebase { push } => stack_rebase_command(app_state, *push),
+                StackCommands::Mr { draft } => stack_mr_command(app_state, project_config, *draft),
+                StackCommands::Retarget => stack_retarget_command(app_state, project_config),
+            },
+        Commands::Sync { continue_sync: true, .. } => sync_continue_command(app_state),
+        Commands::Sync { abort: true, .. } => sync_abort_command(app_state),
+        Commands::Sync { strategy, .. } => sync_command(app_state, project_config, strategy.map(|s| match s {
+            SyncMode::Merge => SyncStrategy::Merge,
+            SyncMode::Rebase => SyncStrategy::Rebase,
+        })),
+        Commands::Completions { .. } => refresh_completions_command(app_state, project_config),
+        Commands::Ui => ui_command(app_state, project_config),
+        Commands::Open { target, print, copy } => open_command(app_state, project_config, *target, *print, *copy),
+        Commands::Cleanup { local, yes } => cleanup_command(app_state, project_config, *local, *yes, app_state.dry_run),
+        Commands::Time(time_command) =>
+            match time_command {
+                TimeCommands::Start { task } => time_start_command(app_state, task),
+                TimeCommands::Stop { task } => time_stop_command(app_state, project_config, task),
+                TimeCommands::Log { duration, task } => time_log_command(app_state, project_config, duration, task),
+                TimeCommands::Report { weeks_ago, .. } => time_report_command(app_state, project_config, *weeks_ago),
+            },
+        Commands::Commit { message, commit_type, all } => commit_command(app_state, project_config, message, commit_type, *all),
+        Commands::Hook(hook_command) =>
+            match hook_command {
+                HookCommands::PrepareCommitMsg { file, source, .. } => prepare_commit_msg_hook(app_state, project_config, file, source),
+                HookCommands::CommitMsg { file } => commit_msg_hook(project_config, file),
+                HookCommands::PrePush { remote, .. } => pre_push_hook(project_config, remote),
+                HookCommands::PostCheckout { flag, .. } => post_checkout_hook(app_state, project_config, flag),
+            },
+        Commands::Hooks(hooks_command) =>
+            match hooks_command {
+                HooksCommands::Install => hooks_install_command(app_state, project_config),
+                HooksCommands::Uninstall => hooks_uninstall_command(app_state),
+                HooksCommands::Status => hooks_status_command(app_state, project_config),
+            },
+        Commands::MergeRequest { command: None, create } => create_mr_command(app_state, project_config, create),
+        Commands::MergeRequest { command: Some(mr_command), .. } =>
+            match mr_command {
+                MrCommands::List { state, all } => list_mrs_command(app_state, project_config, *state, *all),
+                MrCommands::View { iid } => view_mr_command(app_state, project_config, *iid),
+                MrCommands::Approve { iid } => approve_mr_command(app_state, project_config, *iid),
+                MrCommands::Merge { iid, when_pipeline_succeeds, squash, remove_source_branch } => merge_mr_command(app_state, project_config, *iid, *when_pipeline_succeeds, *squash, *remove_source_branch),
+                MrCommands::Rebase { iid } => rebase_mr_command(app_state, project_config, *iid),
+                MrCommands::Close { iid } => set_mr_state_command(app_state, project_config, *iid, true),
+                MrCommands::Reopen { iid } => set_mr_state_command(app_state, project_config, *iid, false),
+                MrCommands::Comments { iid, all, .. } => mr_comments_command(app_state, project_config, *iid, *all),
+                MrCommands::Reply { discussion, body, iid, resolve } => reply_command(app_state, project_config, discussion, body, *iid, *resolve),
+                MrCommands::Resolve { discussion, iid, unresolve } => resolve_command(app_state, project_config, discussion, *iid, *unresolve),
+                MrCommands::Note { body, iid } => note_command(app_state, project_config, body, *iid),
+                MrCommands::Checkout { iid } => checkout_mr_command(app_state, project_config, *iid),
+            },
         _ => {
             return None
         }
@@ -96,7 +211,7 @@ fn process_commands(parsed_command: &Commands, project_config: &ProjectConfig, a
     match res {
         Ok(_) => {}
         Err(err) => {
-            Printer::print_error(err, None);
+            Printer::print_failure(err.code, err.message);
         }
     };
 
@@ -104,63 +219,189 @@ fn process_commands(parsed_command: &Commands, project_config: &ProjectConfig, a
 }
 
 
-fn main() {
-    println!();
-    CombinedLogger::init(
-        vec![
-            WriteLogger::new(LevelFilter::Info, Config::default(), File::create("aworkcli.log").unwrap()),
-        ]
-    ).unwrap();
+/// Уровень лога: --log-level, затем переменная GW_LOG, по умолчанию info
+fn get_log_level(log_level: Option<LogLevel>) -> LevelFilter {
+    match log_level {
+        Some(LogLevel::Off) => LevelFilter::Off,
+        Some(LogLevel::Error) => LevelFilter::Error,
+        Some(LogLevel::Warn) => LevelFilter::Warn,
+        Some(LogLevel::Info) => LevelFilter::Info,
+        Some(LogLevel::Debug) => LevelFilter::Debug,
+        Some(LogLevel::Trace) => LevelFilter::Trace,
+        None => std::env::var("GW_LOG").ok()
+            .and_then(|level| level.parse().ok())
+            .unwrap_or(LevelFilter::Info),
+    }
+}
 
+fn init_logger(printer: &Printer, file_level: LevelFilter) {
+    // Отладочные записи HTTP и TLS библиотек забивают лог, запросы API пишет сам GitlabManager
+    let config = ConfigBuilder::new()
+        .add_filter_ignore_str("hyper")
+        .add_filter_ignore_str("h2")
+        .add_filter_ignore_str("rustls")
+        .add_filter_ignore_str("reqwest")
+        .build();
+
+    let mut loggers: Vec<Box<dyn SharedLogger>> = vec![];
+
+    // Без файла лога работаем дальше: каталог может быть недоступен на запись
+    if let Some(file) = open_log_file() {
+        loggers.push(WriteLogger::new(file_level, config.clone(), file));
+    }
+
+    // С -v записи лога дублируются в stderr, чтобы не искать их в файле
+    let term_level = match printer.verbosity() {
+        Verbosity::Quiet | Verbosity::Normal => None,
+        Verbosity::Verbose => Some(LevelFilter::Info),
+        Verbosity::Trace => Some(LevelFilter::Debug),
+    };
+    if let Some(level) = term_level {
+        let color = if printer.use_color(true) { ColorChoice::Auto } else { ColorChoice::Never };
+        loggers.push(TermLogger::new(level, config, TerminalMode::Stderr, color));
+    }
+
+    let _ = CombinedLogger::init(loggers);
+}
+
+fn main() {
+    // Язык нужен до разбора аргументов: справка clap берется из каталога сообщений
     let config = load_app_config();
-    let parsed = CLI::parse();
+    init_language(config.language);
+
+    // Запрос автодополнения от оболочки (COMPLETE=bash gw -- ...): отвечает и завершает процесс
+    CompleteEnv::with_factory(CLI::command).complete();
+
+    let parsed = match CLI::try_parse() {
+        Ok(parsed) => parsed,
+        Err(err) if matches!(err.kind(), ErrorKind::DisplayHelp | ErrorKind::DisplayVersion) => err.exit(),
+        Err(err) => {
+            // Формат вывода не разобран, поэтому ищем его в аргументах сами
+            let args: Vec<String> = std::env::args().collect();
+            let format = match get_raw_output(&args) {
+                Some("json") => OutputFormat::Json,
+                Some("ndjson") => OutputFormat::Ndjson,
+                _ if args.iter().any(|a| a == "--json") => OutputFormat::Json,
+                _ => err.exit(),
+            };
+
+            Printer::new(format, Verbosity::Normal).install();
+            Printer::print_failure(ErrorCode::InvalidArguments, err.to_string());
+            Printer::finish();
+            std::process::exit(2);
+        }
+    };
+
+    let format = match parsed.output {
+        _ if has_json_alias(&parsed.command) => OutputFormat::Json,
+        OutputMode::Text => OutputFormat::Text,
+        OutputMode::Json => OutputFormat::Json,
+        OutputMode::Ndjson => OutputFormat::Ndjson,
+    };
+    Printer::new(format, Verbosity::from_flags(parsed.quiet, parsed.verbose)).install();
+    init_logger(Printer::current(), get_log_level(parsed.log_level));
+
+    run(parsed.command, config, parsed.dry_run);
+
+    // Код возврата нужен скриптам и хукам git при любом формате вывода
+    if !Printer::finish() {
+        std::process::exit(1);
+    }
+}
+
+/// Значение --output из неразобранных аргументов: `--output json` или `--output=json`, последнее побеждает
+fn get_raw_output(args: &[String]) -> Option<&str> {
+    let mut output = None;
+    for (i, arg) in args.iter().enumerate() {
+        if arg == "--" {
+            break;
+        }
+        if arg == "--output" {
+            output = args.get(i + 1).map(|a| a.as_str());
+        } else if let Some(value) = arg.strip_prefix("--output=") {
+            output = Some(value);
+        }
+    }
+
+    output
+}
+
+/// Скрытый --json команд, где он был до --output
+fn has_json_alias(command: &Option<Commands>) -> bool {
+    matches!(command,
+        Some(Commands::Tasks { json: true, .. })
+        | Some(Commands::MergeRequest { command: Some(MrCommands::Comments { json: true, .. }), .. })
+        | Some(Commands::Time(TimeCommands::Report { json: true, .. })))
+}
+
+/// Команды, которые умеют показывать план без выполнения
+fn supports_dry_run(command: &Commands) -> bool {
+    matches!(command,
+        Commands::Init
+        | Commands::GlobalConfig(_)
+        | Commands::HelpGen { .. }
+        | Commands::CheckoutTask { .. }
+        | Commands::CheckoutFeature { .. }
+        | Commands::Done { .. }
+        | Commands::Task(TaskCommands::New { .. })
+        | Commands::MergeRequest { command: None, .. }
+        | Commands::MergeRequest { command: Some(
+            MrCommands::Approve { .. } | MrCommands::Merge { .. } | MrCommands::Rebase { .. } | MrCommands::Close { .. }
+            | MrCommands::Reopen { .. } | MrCommands::Reply { .. } | MrCommands::Resolve { .. } | MrCommands::Note { .. }
+            | MrCommands::Checkout { .. }
+        ), .. }
+        | Commands::Stack(StackCommands::New { .. } | StackCommands::SetParent { .. } | StackCommands::Rebase { .. } | StackCommands::Mr { .. } | StackCommands::Retarget)
+        | Commands::Sync { continue_sync: false, abort: false, .. }
+        | Commands::Cleanup { .. }
+        | Commands::Time(TimeCommands::Start { .. } | TimeCommands::Stop { .. } | TimeCommands::Log { .. })
+        | Commands::Commit { .. }
+        | Commands::Hooks(HooksCommands::Install | HooksCommands::Uninstall))
+}
+
+fn run(command: Option<Commands>, config: AppConfig, dry_run: bool) {
 
     let current_dir = match std::env::current_dir() {
         Ok(path) => path,
         Err(err) => {
             error!("{}", err);
+            Printer::print_failure(ErrorCode::CommandFailed, err.to_string());
             return;
         }
     };
 
-    let parsed_command = match parsed.command {
+    let parsed_command = match command {
         None => {
-            Printer::print_error("Команда не найдена".to_string(), None);
+            Printer::print_failure(ErrorCode::InvalidArguments, t!("command-not-found"));
             return;
         }
         Some(command) => command,
     };
 
-
-    match process_core_commands(&parsed_command, &config) {
-        Some(_) => { return; }
-        None => {}
+    if dry_run && !supports_dry_run(&parsed_command) {
+        Printer::print_failure(ErrorCode::Unsupported, t!("dry-run-unsupported"));
+        return;
     }
 
+    if process_core_commands(&parsed_command, &config, dry_run).is_some() {
+        return;
+    }
 
-    let app_state = match get_app_state(config.clone(), current_dir) {
+    let app_state = match get_app_state(config.clone(), current_dir, dry_run) {
         None => { return; }
         Some(state) => { state }
     };
 
-    match process_base_commands(&parsed_command, &config, &app_state) {
-        Some(_) => { return; }
-        None => {}
+    if process_base_commands(&parsed_command, &app_state).is_some() {
+        return;
     }
 
     let project_config = match load_project_config(app_state.path.to_path_buf()) {
         Ok(pc) => pc,
         Err(error) => {
-            Printer::print_error(error, None);
+            Printer::print_failure(ErrorCode::Config, error);
             return;
         }
     };
 
-    match process_commands(&parsed_command, &project_config, &app_state) {
-        Some(_) => { return; }
-        None => {}
-    }
-
-    println!();
+    process_commands(&parsed_command, &project_config, &app_state);
 }
-
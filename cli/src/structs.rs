@@ -1,12 +1,62 @@
 use std::path::PathBuf;
-use helpers::AppConfig;
+use helpers::{t, AppConfig, AppError, ErrorCode, Printer, ProjectConfig};
 use managers::git::GitManager;
-use managers::gitlab::GitlabManager;
+use managers::gitlab::{GitlabManager, GitlabTracker};
+use managers::jira::JiraManager;
+use managers::tracker::{FeatureKey, IssueTracker, TaskKey};
 
 pub struct AppState {
     pub app_config: AppConfig,
     pub gitlab_manager: GitlabManager,
     pub git_manager: GitManager,
+    pub jira_manager: Option<JiraManager>,
     pub path: PathBuf,
+    /// --dry-run: операции git и изменения в GitLab только выводятся
+    pub dry_run: bool,
 }
 
+impl AppState {
+    pub fn get_task_tracker(&self, task: &TaskKey, project_config: &ProjectConfig) -> Result<Box<dyn IssueTracker + '_>, AppError> {
+        match task {
+            TaskKey::Gitlab(_) => Ok(Box::new(self.get_gitlab_tracker(project_config))),
+            TaskKey::Jira(_) => Ok(Box::new(self.get_jira_manager()?.clone())),
+        }
+    }
+
+    pub fn get_feature_tracker(&self, feature: &FeatureKey, project_config: &ProjectConfig) -> Result<Box<dyn IssueTracker + '_>, AppError> {
+        match feature {
+            FeatureKey::Gitlab(_) => Ok(Box::new(self.get_gitlab_tracker(project_config))),
+            FeatureKey::Jira(_) => Ok(Box::new(self.get_jira_manager()?.clone())),
+        }
+    }
+
+    /// Выводит изменение в GitLab, Jira или файлах в режиме --dry-run. Возвращает true, если выполнять его не нужно
+    pub fn plan(&self, text: String) -> bool {
+        if self.dry_run {
+            Printer::print_plan(text, None);
+        }
+
+        self.dry_run
+    }
+
+    /// Задача из аргумента команды или по текущей ветке
+    pub fn get_task_key(&self, task: &Option<String>) -> Result<TaskKey, AppError> {
+        if let Some(raw) = task {
+            return TaskKey::parse(raw);
+        }
+
+        let branch = self.git_manager.get_current_branch()?;
+        TaskKey::from_branch(branch.as_str()).ok_or_else(|| AppError::new(ErrorCode::InvalidTaskKey, t!("task-from-branch-failed", branch = branch)))
+    }
+
+    fn get_gitlab_tracker(&self, project_config: &ProjectConfig) -> GitlabTracker<'_> {
+        GitlabTracker::new(&self.gitlab_manager, project_config.project_id, project_config.group_id)
+    }
+
+    fn get_jira_manager(&self) -> Result<&JiraManager, AppError> {
+        match &self.jira_manager {
+            Some(jm) => Ok(jm),
+            None => Err(AppError::new(ErrorCode::Config, t!("jira-not-configured"))),
+        }
+    }
+}
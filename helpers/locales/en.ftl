@@ -0,0 +1,418 @@
+## Configs and editor
+
+editor-not-set = Editor is not set
+editor-failed = Editor exited with an error: { $status }
+editor-launch-failed = Failed to launch editor { $editor }: { $error }
+project-config-read-failed = Failed to read the project config.
+project-config-open-failed =
+    Failed to open the project config.
+    Try running the init command
+config-dir-not-found = Config directory not found
+config-file-open-failed = Failed to open the file
+
+## Common
+
+command-not-found = Command not found
+repository-dirty = The repository has uncommitted changes!
+switching-to-branch = Switching to branch { $branch }
+task-from-branch-failed = Failed to detect the task from branch { $branch }
+task-fetch-failed = Failed to fetch task { $task }: { $error }
+feature-fetch-failed = Failed to fetch feature { $feature }: { $error }
+jira-not-configured = Jira is not configured. Run gc set-jira
+rebase-branch = Rebasing { $branch } onto { $onto }
+workflow-update-failed = Failed to update the task status: { $error }
+dry-run-unsupported = This command does not support --dry-run
+dry-run-update-issue = Update task #{ $iid }: { $changes }
+dry-run-assign-me = assign to me
+dry-run-close = close
+dry-run-approve-mr = Approve MR !{ $iid }
+dry-run-merge-mr = Merge MR !{ $iid } ({ $source } → { $target })
+dry-run-rebase-mr = Start rebase of MR !{ $iid } onto { $target }
+dry-run-close-mr = Close MR !{ $iid }
+dry-run-reopen-mr = Reopen MR !{ $iid }
+dry-run-retarget-mr = Retarget MR !{ $iid } ({ $branch }) to { $target }
+dry-run-note = Add a comment to MR !{ $iid }
+dry-run-reply = Reply in discussion { $discussion } of MR !{ $iid }
+dry-run-resolve = Resolve discussion { $discussion } of MR !{ $iid }
+dry-run-unresolve = Unresolve discussion { $discussion } of MR !{ $iid }
+dry-run-create-issue = Create task "{ $title }"
+dry-run-checkout-new-task = Create branch <iid>-task from { $parent } and switch to it
+dry-run-start-timer = Start timer for { $task }
+dry-run-stop-timer = Stop timer for { $task } and log { $duration }
+dry-run-spend-time = Log { $duration } on { $task }
+dry-run-install-hook = Install hook { $hook } to { $path }
+dry-run-uninstall-hook = Remove hook { $hook } from { $path }
+dry-run-save-app-config = Write the global config to { $path }
+dry-run-save-project-config = Write the project config to { $path }
+dry-run-help-gen = Write man pages to { $man } and the reference to { $markdown }
+
+## Issue trackers
+
+tracker-invalid-task = Unrecognized task { $task }. Expected a GitLab issue iid or a Jira key (PROJ-123)
+tracker-invalid-feature = Unrecognized feature { $feature }. Expected a GitLab epic iid or a Jira key (PROJ-123)
+jira-token-not-set = Jira token is not set
+jira-request-failed = Jira returned error { $status } for issue { $key }
+jira-not-jira-task = Task { $task } is not a Jira issue
+jira-not-jira-epic = Epic { $epic } is not a Jira epic
+gitlab-token-not-set = Token is not set
+gitlab-not-gitlab-task = Task { $task } is not a GitLab issue
+gitlab-not-gitlab-epic = Epic { $epic } is not a GitLab epic
+gitlab-milestone-not-found = Milestone { $milestone } not found
+
+## Git
+
+git-remote-url-not-found = Repository URL not found
+git-current-branch-unknown = Failed to detect the current branch
+git-branch-not-found-creating = Branch { $branch } not found. Switching to the parent ({ $parent }) and creating the target branch...
+git-branch-not-found = Branch { $branch } not found (nor { $remote })
+git-commit-failed = git commit failed
+git-checkout-failed = Failed to switch to branch { $branch }
+git-pull-failed = git pull failed
+
+## Tasks and features
+
+checkout-task-branch-exists = Branch { $branch } already exists, switching
+checkout-task-fetching = Fetching the task!
+checkout-task-issue = Task: { $title } ({ $url })
+checkout-task-no-epic = The task has no epic. Starting from master
+checkout-feature-fetching = Fetching the feature
+checkout-feature-epic = Epic: { $title } ({ $url })
+checkout-feature-tech-debt = Tech debt epic. Switching to master
+create-task-opening-editor = Opening the editor for the task description...
+create-task-creating = Creating the task...
+create-task-created = Created task #{ $iid } ({ $url })
+done-finishing = Finishing task { $task }
+done-finished = Task { $task } is done!
+epic-tree-fetching = Fetching the epic tree...
+epic-tree-no-my-issues = The epic has no issues assigned to you
+epic-tree-unassigned = unassigned
+list-tasks-not-found = No tasks found
+list-tasks-prompt = Task
+workflow-gitlab-only = Status changes are supported for GitLab issues only, { $task } skipped
+workflow-no-labels = none
+workflow-issue-state = Task #{ $iid } [{ $state }], labels: { $labels }
+
+## Init and global config
+
+init-host-mismatch = The project repository host differs from { $host }
+init-fetching = Fetching the project...
+init-empty-remote = Empty repository URL
+init-group = Group: { $name } ({ $url })
+init-project = Project: { $name } ({ $url })
+init-saving = Saving the config...
+init-success = Project initialized!
+init-save-failed =
+    Failed to save the project config:
+    { $error }
+update-host-success = Host updated!
+update-jira-success = Jira settings updated!
+update-token-success = Token updated!
+update-language-success = Language updated!
+
+## MR description
+
+mr-template-error = MR template error: { $error }
+mr-template-read-failed = Failed to read template { $path }: { $error }
+mr-templates-not-found = No MR templates found in { $dir }
+mr-template-prompt = MR template
+
+## Merge requests
+
+create-mr-source = Source branch: { $branch }
+create-mr-detecting-target = Detecting the target branch
+create-mr-target-unknown = Failed to detect the target branch
+create-mr-target = Target branch: { $branch }
+create-mr-creating = Creating the MR...
+create-mr-created = Created MR !{ $iid } ({ $url })
+create-mr-planned = Create MR { $source } -> { $target }: { $title }
+mr-not-found-for-branch = No open MR found for branch { $branch }
+mr-list-empty = No MRs found
+mr-list-conflicts = conflicts
+mr-view-none = none
+mr-view-url = URL: { $url }
+mr-view-state = State: { $state }
+mr-view-branches = Branches: { $source } → { $target }
+mr-view-author = Author: @{ $author }
+mr-view-assignees = Assignees: { $users }
+mr-view-reviewers = Reviewers: { $users }
+mr-view-pipeline = Pipeline: { $status } ({ $url })
+mr-view-no-pipeline = Pipeline: none
+mr-view-merge-status = Merge status: { $status }
+mr-view-conflicts = The MR has conflicts
+mr-view-approvals = Approvals: { $users } ({ $left } of { $required } left)
+mr-approved = MR !{ $iid } approved
+mr-merge-conflicts = MR !{ $iid } has conflicts and cannot be merged
+mr-merged = MR !{ $iid } merged
+mr-merge-scheduled = MR !{ $iid } will be merged when the pipeline succeeds
+mr-rebase-started = Started rebasing MR !{ $iid } onto { $target }
+mr-checkout-fork = MR from a fork. Fetching changes into branch { $branch }
+mr-comments-none = No unresolved discussions
+mr-comments-general = General
+mr-comments-line = line { $line }
+mr-comments-resolved = resolved
+mr-comments-discussion = discussion { $id }
+mr-reply-added = Reply added to discussion { $discussion }
+mr-discussion-unresolved = Discussion { $discussion } reopened
+mr-discussion-resolved = Discussion { $discussion } resolved
+mr-note-added = Comment added ({ $url })
+mr-comment-empty = Empty comment
+
+## Branch stack
+
+stack-branch-not-in-stack = Branch { $branch } is not part of a stack
+stack-branch-not-in-stack-hint = Branch { $branch } is not part of a stack. Create a branch with gw stack new or set its parent with gw stack set-parent
+stack-mr-wrong-target = targets { $target }, expected { $expected }
+stack-no-mr = no MR
+stack-branch-exists = Branch { $branch } already exists. To add it to the stack, run gw stack set-parent
+stack-branch-created = Branch { $branch } created on top of { $parent }
+stack-parent-cycle = Branch { $branch } is already an ancestor of { $parent }
+stack-parent-set = Parent of branch { $branch }: { $parent }
+stack-rebase-conflict =
+    Conflict while rebasing { $branch } onto { $onto }: { $error }
+    Resolve the conflicts, run git rebase --continue and repeat gw stack rebase
+stack-rebased = Stack updated
+stack-mr-exists = MR for { $branch } already exists: !{ $iid }
+stack-mr-retarget-hint = MR !{ $iid } targets { $target } instead of { $expected }. Run gw stack retarget
+stack-rebase-hint = Run gw stack rebase to move the branches onto their new parents
+stack-no-merged-parents = No merged parents in the stack
+stack-mr-retargeted = MR !{ $iid } ({ $branch }) retargeted to { $target }
+
+## Sync
+
+sync-in-progress = A sync is already in progress. Use gw sync --continue or gw sync --abort
+sync-unresolved-conflicts = Unresolved conflicts remain: { $files }
+sync-aborted-partial = Branches that were already synced stay updated: { $branches }
+sync-aborted = Sync aborted
+sync-merging = Merging { $parent } into { $branch }
+sync-step-failed = Failed to update { $branch } from { $parent }: { $error }
+sync-conflicts = Conflicts:
+sync-resolve-hint = Resolve the conflicts, stage the files (git add) and run gw sync --continue. To cancel: gw sync --abort
+sync-not-task-branch = Branch { $branch } is not a feature or task branch
+sync-task-without-epic = Task { $task } has no epic
+sync-tech-debt = Tech debt tasks branch off master, there is no feature branch
+sync-updating = Updating { $branch }
+sync-using-remote = Local branch { $branch } is not updated, using origin/{ $branch }
+sync-no-state = No interrupted sync
+
+## Branch cleanup
+
+cleanup-prune-failed = Failed to update remote branches: { $error }
+cleanup-protected-failed = Failed to fetch protected branches: { $error }
+cleanup-checking = Checking { $count } branches
+cleanup-skipping = Skipping { $branch }: { $error }
+cleanup-nothing = No branches to delete
+cleanup-use-yes = Use --yes to delete without confirmation
+cleanup-confirm = Delete { $count } branches?
+cleanup-deleted-local = Deleted local branch { $branch }
+cleanup-delete-local-failed = Failed to delete local branch { $branch }: { $error }
+cleanup-deleted-remote = Deleted branch origin/{ $branch }
+cleanup-delete-remote-failed = Failed to delete branch origin/{ $branch }: { $error }
+cleanup-reason-issue-closed = issue #{ $iid } closed
+cleanup-reason-epic-closed = epic &{ $iid } closed
+cleanup-header-branch = Branch
+cleanup-header-location = Where
+cleanup-header-reason = Reason
+
+## Time tracking
+
+time-timer-already-started = Timer for task { $task } already started at { $time }
+time-timer-started = Timer for task { $task } started at { $time }
+time-timer-not-started = Timer for task { $task } is not running
+time-too-short = Less than a minute passed, no time logged for task { $task }
+time-logged = Logged { $duration } on task #{ $iid }. Total: { $total }
+time-jira-unsupported = Time logging is not supported for Jira. Time for task { $task }: { $duration }
+time-jira-timer-unsupported = Timers are not supported for Jira tasks: time for { $task } cannot be logged
+time-no-epic = No epic
+time-week = Week { $start } — { $end }
+time-no-entries = No time logged
+time-total = Total: { $total }
+
+## Commits and hooks
+
+commit-message-read-failed = Failed to read the commit message: { $error }
+commit-message-write-failed = Failed to write the commit message: { $error }
+commit-pattern-invalid = Invalid commit message pattern { $pattern }: { $error }
+commit-pattern-mismatch = Commit message "{ $subject }" does not match pattern { $pattern }
+hooks-unsupported = Hook { $hook } is not supported. Available: { $available }
+hooks-skipped = Hook { $hook } skipped: { $path } already exists
+hooks-chaining = The existing { $hook } hook will be called from the gw hook
+hooks-installed = Installed hook { $hook }
+hooks-removed-restored = Hook { $hook } removed, the original hook restored
+hooks-removed = Hook { $hook } removed
+hooks-dir = Hooks directory: { $path }
+hooks-state-installed = installed
+hooks-state-foreign = foreign hook
+hooks-state-missing = not installed
+hooks-state-disabled = disabled in config
+hooks-state-chained = calls the original hook
+hooks-protected-push = Pushing to protected branch { $remote }/{ $branch } is not allowed. Create an MR: gw mr
+hooks-branch-name = Branch { $branch } does not follow the naming pattern (<task>-task, feature/<epic>)
+hooks-task = Task { $task }: { $title } ({ $url })
+hooks-branch-pattern-invalid = Invalid branch name pattern { $pattern }: { $error }
+
+## Log
+
+logs-dir-not-found = Log directory not found
+logs-empty = The log is empty so far: { $path }
+
+## Completion
+
+completions-shell-unsupported = Shell { $shell } is not supported
+completions-refreshed = Completion cache updated: { $issues } issues, { $epics } epics
+
+## Documentation
+
+help-gen-man-written = Man pages: { $count }, directory { $path }
+help-gen-markdown-written = Command reference: { $path }
+help-gen-markdown-title = gw commands
+help-gen-aliases-section = ALIASES
+
+## Dashboard
+
+ui-requires-terminal = The dashboard only works in a terminal with text output
+ui-title = gw: branch { $branch }
+ui-panel-issues = My issues
+ui-panel-my-mrs = My MRs
+ui-panel-reviews = Awaiting my review
+ui-panel-branches = Task branches
+ui-help = Tab panel · ↑↓ select · Enter checkout · o open · m create MR · r refresh · q quit
+ui-refreshing = Refreshing...
+ui-press-enter = Press Enter to return to the dashboard
+ui-mr-exists = The MR already exists
+ui-opened = Opened: { $url }
+ui-nothing-to-open = Nothing to open
+
+## Open in browser
+
+open-opening = Opening { $url }
+open-copied = Copied: { $url }
+open-epic-not-found = Task { $task } has no epic
+open-pipeline-not-found = MR !{ $iid } has no pipeline
+
+## Commands and arguments
+
+arg-output = Output format: text, a single JSON document or JSON lines as the command runs
+arg-quiet = Print only errors and the command result
+arg-verbose = Verbose output: -v debug messages and log, -vv debug level log
+arg-log-level = Log file level. Taken from GW_LOG by default, otherwise info
+arg-dry-run = Show the planned git, API and file operations without performing them. Not supported by ui, open, completions --refresh, sync --continue and sync --abort
+cmd-init = Initialize the project
+cmd-global-config = Global configuration
+cmd-checkout-task = Switch to the task branch
+arg-task-key = GitLab issue iid or Jira issue key (PROJ-123)
+cmd-checkout-feature = Switch to the feature branch
+arg-feature-key = GitLab epic iid or Jira epic key (PROJ-123)
+cmd-tasks = My tasks in the project, pick one to switch to
+arg-milestone = Milestone
+arg-label = Label (can be repeated)
+arg-tasks-state = Task state
+arg-epic-iid = Epic iid
+cmd-done = Finish the task according to the project workflow
+arg-task-from-branch = Task. Detected from the current branch by default
+cmd-task = Tasks
+cmd-epic = Epics
+cmd-merge-request = Create or manage MRs
+cmd-stack = Stack of dependent task branches
+cmd-sync = Update the feature branch from the base branch and propagate changes to task branches
+arg-sync-strategy = Update strategy. Taken from the project config by default
+arg-sync-continue = Continue after resolving conflicts
+arg-sync-abort = Abort an interrupted sync
+cmd-cleanup = Delete task and feature branches with merged MRs or closed issues
+arg-cleanup-local = Local branches only
+arg-cleanup-yes = Delete without confirmation
+cmd-time = Task time tracking
+cmd-commit = Commit with a reference to the current branch task
+arg-commit-message = Commit message. Opens the editor if omitted
+arg-commit-type = Conventional Commits type. Detected from the task labels by default
+arg-commit-all = Stage all modified files (git commit -a)
+cmd-hooks = Project git hooks
+arg-mr-create-source = Source branch
+arg-mr-create-target = Target branch
+arg-mr-create-review = Add reviewers to the MR
+arg-mr-create-template = Description template from .gitlab/merge_request_templates or a file path. Without a value - pick from a list
+arg-mr-create-edit = Open the description in $EDITOR before creating the MR
+arg-mr-create-draft = Create the MR as a draft
+arg-mr-create-no-draft = Create the MR as ready even if drafts are the project default
+arg-mr-create-labels = MR label (can be repeated)
+arg-mr-create-copy-labels = Copy labels from the task
+arg-mr-create-squash = Squash commits on merge
+arg-mr-create-no-squash = Do not squash commits on merge
+arg-mr-create-remove-source-branch = Delete the source branch after merge
+arg-mr-create-keep-source-branch = Keep the source branch after merge
+arg-mr-create-allow-collaboration = Allow commits from members of the target project (for forks)
+arg-mr-create-target-project = Target project id when the MR is created from a fork
+cmd-gc-set-token = Set the token
+cmd-gc-set-host = Set the GitLab host
+cmd-gc-set-jira = Configure Jira as the task source
+arg-gc-set-jira-host = Jira URL, e.g. https://jira.example.com
+arg-gc-set-jira-token = Jira token
+arg-gc-set-jira-user = User email or login (for Jira Cloud)
+arg-gc-set-jira-epic-link-field = Epic Link field, e.g. customfield_10014
+cmd-gc-set-language = Language of messages and help
+arg-gc-set-language-language = Language
+cmd-mr-list = Project MRs
+arg-mr-list-state = MR state
+arg-mr-list-all = Show MRs of all authors, not only mine
+cmd-mr-view = MR details, approvals and merge status
+arg-mr-iid = MR iid. The current branch MR by default
+cmd-mr-approve = Approve the MR
+cmd-mr-merge = Merge the MR
+arg-mr-merge-when-pipeline-succeeds = Merge when the pipeline succeeds
+arg-mr-merge-squash = Squash commits
+arg-mr-merge-remove-source-branch = Delete the source branch
+cmd-mr-rebase = Rebase the MR onto the target branch in GitLab
+cmd-mr-close = Close the MR
+cmd-mr-reopen = Reopen the MR
+cmd-mr-comments = Unresolved MR discussions by file and line
+arg-mr-comments-all = Also show resolved discussions and general comments
+cmd-mr-reply = Reply to an MR discussion
+arg-discussion = Discussion id
+arg-mr-reply-body = Reply text. Opens $EDITOR if omitted
+arg-mr-reply-resolve = Resolve the discussion after replying
+cmd-mr-resolve = Resolve an MR discussion
+arg-mr-resolve-unresolve = Reopen the discussion
+cmd-mr-note = Leave a general comment on the MR
+arg-mr-note-body = Comment text. Opens $EDITOR if omitted
+cmd-mr-checkout = Check out the MR source branch locally (including forks)
+arg-mr-checkout-iid = MR iid
+cmd-stack-show = Show the branch chain of the current stack and their MRs
+cmd-stack-new = Create a task branch on top of the current branch
+cmd-stack-set-parent = Set the parent branch
+arg-stack-set-parent-parent = Parent branch
+arg-stack-set-parent-branch = Branch. The current one by default
+cmd-stack-rebase = Rebase stack branches onto their parents in order
+arg-stack-rebase-push = Push the branches to origin (--force-with-lease)
+cmd-stack-mr = Create an MR for each stack branch into its parent
+arg-stack-mr-draft = Create the MRs as drafts
+cmd-stack-retarget = Retarget MRs whose parents are already merged
+cmd-time-start = Start the task timer
+cmd-time-stop = Stop the timer and log the time on the task
+cmd-time-log = Log time on the task manually
+arg-time-log-duration = Duration in GitLab format, e.g. 1h30m
+cmd-time-report = Weekly report of logged time by task and epic
+arg-time-report-weeks-ago = How many weeks ago. 0 - the current week
+cmd-hooks-install = Install hooks from the project config. Existing hooks are called before gw hooks
+cmd-hooks-uninstall = Remove gw hooks and restore the original ones
+cmd-hooks-status = Hooks status
+cmd-task-new = Create a task and assign it to yourself
+arg-task-new-title = Task title
+arg-task-new-description = Task description. Opens $EDITOR if omitted
+arg-task-new-weight = Task weight
+arg-task-new-epic = Parent epic iid
+arg-task-new-checkout = Switch to the task branch right away
+cmd-epic-tree = Tree of child epics and issues
+arg-epic-tree-mine = Show only my issues
+cmd-logs = Show the latest log records
+arg-logs-lines = Number of lines
+arg-logs-follow = Follow new records
+arg-logs-path = Only print the log file path
+cmd-completions = Completion script for bash, zsh, fish, elvish or PowerShell
+arg-completions-shell = Shell
+arg-completions-refresh = Refresh the cached issues and epics used for completion
+cmd-help-gen = Generate man pages and a markdown command reference
+arg-help-gen-out = Output directory for the documentation
+cmd-ui = Terminal dashboard with issues, MRs and branches
+cmd-open = Open the issue, epic, MR, pipeline or project of the current branch in the browser
+arg-open-target = What to open
+arg-open-print = Only print the URL
+arg-open-copy = Copy the URL to the clipboard
@@ -0,0 +1,418 @@
+## Конфиги и редактор
+
+editor-not-set = Не указан редактор
+editor-failed = Редактор завершился с ошибкой: { $status }
+editor-launch-failed = Не удалось запустить редактор { $editor }: { $error }
+project-config-read-failed = Не удалось прочитать конфиг проекта.
+project-config-open-failed =
+    Не удалось открыть конфиг проекта.
+    Попробуйте выполнить команду init
+config-dir-not-found = Не удалось найти каталог конфига
+config-file-open-failed = Ошибка открытия файла
+
+## Общие
+
+command-not-found = Команда не найдена
+repository-dirty = Репозиторий содержит несохраненные файлы!
+switching-to-branch = Переключаюсь на ветку { $branch }
+task-from-branch-failed = Не удалось определить задачу по ветке { $branch }
+task-fetch-failed = Не удалось получить задачу { $task }: { $error }
+feature-fetch-failed = Не удалось получить эпик { $feature }: { $error }
+jira-not-configured = Jira не настроена. Выполните команду gc set-jira
+rebase-branch = Ребейз { $branch } на { $onto }
+workflow-update-failed = Не удалось обновить статус задачи: { $error }
+dry-run-unsupported = Команда не поддерживает --dry-run
+dry-run-update-issue = Обновить задачу #{ $iid }: { $changes }
+dry-run-assign-me = назначить на себя
+dry-run-close = закрыть
+dry-run-approve-mr = Одобрить MR !{ $iid }
+dry-run-merge-mr = Смержить MR !{ $iid } ({ $source } → { $target })
+dry-run-rebase-mr = Запустить rebase MR !{ $iid } на { $target }
+dry-run-close-mr = Закрыть MR !{ $iid }
+dry-run-reopen-mr = Переоткрыть MR !{ $iid }
+dry-run-retarget-mr = Перенести MR !{ $iid } ({ $branch }) на { $target }
+dry-run-note = Добавить комментарий к MR !{ $iid }
+dry-run-reply = Ответить в обсуждении { $discussion } MR !{ $iid }
+dry-run-resolve = Закрыть обсуждение { $discussion } MR !{ $iid }
+dry-run-unresolve = Переоткрыть обсуждение { $discussion } MR !{ $iid }
+dry-run-create-issue = Создать задачу «{ $title }»
+dry-run-checkout-new-task = Создать ветку <iid>-task от { $parent } и переключиться на нее
+dry-run-start-timer = Запустить таймер по { $task }
+dry-run-stop-timer = Остановить таймер по { $task } и списать { $duration }
+dry-run-spend-time = Списать { $duration } на { $task }
+dry-run-install-hook = Установить хук { $hook } в { $path }
+dry-run-uninstall-hook = Удалить хук { $hook } из { $path }
+dry-run-save-app-config = Записать глобальный конфиг в { $path }
+dry-run-save-project-config = Записать конфиг проекта в { $path }
+dry-run-help-gen = Записать man страницы в { $man } и справку в { $markdown }
+
+## Трекеры задач
+
+tracker-invalid-task = Не удалось распознать задачу { $task }. Ожидается iid задачи GitLab или ключ Jira (PROJ-123)
+tracker-invalid-feature = Не удалось распознать фичу { $feature }. Ожидается iid эпика GitLab или ключ Jira (PROJ-123)
+jira-token-not-set = Не указан токен Jira
+jira-request-failed = Jira вернула ошибку { $status } для задачи { $key }
+jira-not-jira-task = Задача { $task } не относится к Jira
+jira-not-jira-epic = Эпик { $epic } не относится к Jira
+gitlab-token-not-set = Не указан токен
+gitlab-not-gitlab-task = Задача { $task } не относится к GitLab
+gitlab-not-gitlab-epic = Эпик { $epic } не относится к GitLab
+gitlab-milestone-not-found = Майлстоун { $milestone } не найден
+
+## Git
+
+git-remote-url-not-found = Не удалось найти ссылку на репозиторий
+git-current-branch-unknown = Не удалось определить текущую ветку
+git-branch-not-found-creating = Ветка { $branch } не найдена. Переключаюсь на родительскую ({ $parent }) и создаю целевую ветку...
+git-branch-not-found = Не удалось найти ветку { $branch } (или { $remote })
+git-commit-failed = git commit завершился с ошибкой
+git-checkout-failed = Не удалось переключиться на ветку { $branch }
+git-pull-failed = git pull завершился с ошибкой
+
+## Задачи и фичи
+
+checkout-task-branch-exists = Ветка { $branch } уже существует, переключаюсь
+checkout-task-fetching = Получаю информацию о задаче!
+checkout-task-issue = Задача: { $title } ({ $url })
+checkout-task-no-epic = У задачи не указан эпик. Начинаю от мастера
+checkout-feature-fetching = Получаю информацию о фиче
+checkout-feature-epic = Эпик: { $title } ({ $url })
+checkout-feature-tech-debt = Эпик техдолговый. Переключаю на ветку master
+create-task-opening-editor = Открываю редактор для описания задачи...
+create-task-creating = Создаю задачу...
+create-task-created = Создана задача #{ $iid } ({ $url })
+done-finishing = Завершаю задачу { $task }
+done-finished = Задача { $task } завершена!
+epic-tree-fetching = Получаю дерево эпика...
+epic-tree-no-my-issues = В эпике нет задач, назначенных на вас
+epic-tree-unassigned = не назначена
+list-tasks-not-found = Задачи не найдены
+list-tasks-prompt = Задача
+workflow-gitlab-only = Смена статуса поддерживается только для задач GitLab, { $task } пропущена
+workflow-no-labels = нет
+workflow-issue-state = Задача #{ $iid } [{ $state }], метки: { $labels }
+
+## Инициализация и глобальный конфиг
+
+init-host-mismatch = Хост репозитория проекта отличен от { $host }
+init-fetching = Получаю информацию о проекте...
+init-empty-remote = Пустой адрес репозитория
+init-group = Группа: { $name } ({ $url })
+init-project = Проект: { $name } ({ $url })
+init-saving = Сохраняю конфиг...
+init-success = Проект успешно инициализирован!
+init-save-failed =
+    Ошибка при сохранении конфигурации проекта:
+    { $error }
+update-host-success = Хост успешно обновлен!
+update-jira-success = Настройки Jira успешно обновлены!
+update-token-success = Токен успешно обновлен!
+update-language-success = Язык успешно обновлен!
+
+## Описание MR
+
+mr-template-error = Ошибка в шаблоне MR: { $error }
+mr-template-read-failed = Не удалось прочитать шаблон { $path }: { $error }
+mr-templates-not-found = Шаблоны MR не найдены в { $dir }
+mr-template-prompt = Шаблон MR
+
+## Merge requests
+
+create-mr-source = Исходная ветка: { $branch }
+create-mr-detecting-target = Определяю целевую ветку
+create-mr-target-unknown = Не удалось определить целевую ветку
+create-mr-target = Целевая ветка: { $branch }
+create-mr-creating = Создаю MR...
+create-mr-created = Создан MR !{ $iid } ({ $url })
+create-mr-planned = Создать MR { $source } -> { $target }: { $title }
+mr-not-found-for-branch = Открытый MR для ветки { $branch } не найден
+mr-list-empty = MR не найдены
+mr-list-conflicts = конфликты
+mr-view-none = нет
+mr-view-url = Ссылка: { $url }
+mr-view-state = Состояние: { $state }
+mr-view-branches = Ветки: { $source } → { $target }
+mr-view-author = Автор: @{ $author }
+mr-view-assignees = Исполнители: { $users }
+mr-view-reviewers = Ревьюверы: { $users }
+mr-view-pipeline = Пайплайн: { $status } ({ $url })
+mr-view-no-pipeline = Пайплайн: нет
+mr-view-merge-status = Статус мержа: { $status }
+mr-view-conflicts = В MR есть конфликты
+mr-view-approvals = Апрувы: { $users } (осталось { $left } из { $required })
+mr-approved = MR !{ $iid } апрувнут
+mr-merge-conflicts = В MR !{ $iid } есть конфликты, мерж невозможен
+mr-merged = MR !{ $iid } смержен
+mr-merge-scheduled = MR !{ $iid } будет смержен после успешного пайплайна
+mr-rebase-started = Запущен ребейз MR !{ $iid } на { $target }
+mr-checkout-fork = MR из форка. Забираю изменения в ветку { $branch }
+mr-comments-none = Неразрешенных обсуждений нет
+mr-comments-general = Общие
+mr-comments-line = строка { $line }
+mr-comments-resolved = разрешено
+mr-comments-discussion = обсуждение { $id }
+mr-reply-added = Ответ добавлен в обсуждение { $discussion }
+mr-discussion-unresolved = Обсуждение { $discussion } снова открыто
+mr-discussion-resolved = Обсуждение { $discussion } разрешено
+mr-note-added = Комментарий добавлен ({ $url })
+mr-comment-empty = Пустой комментарий
+
+## Стек веток
+
+stack-branch-not-in-stack = Ветка { $branch } не входит в стек
+stack-branch-not-in-stack-hint = Ветка { $branch } не входит в стек. Создайте ветку командой gw stack new или укажите родителя gw stack set-parent
+stack-mr-wrong-target = цель { $target }, ожидается { $expected }
+stack-no-mr = нет MR
+stack-branch-exists = Ветка { $branch } уже существует. Чтобы добавить ее в стек, выполните gw stack set-parent
+stack-branch-created = Ветка { $branch } создана поверх { $parent }
+stack-parent-cycle = Ветка { $branch } уже является предком { $parent }
+stack-parent-set = Родитель ветки { $branch }: { $parent }
+stack-rebase-conflict =
+    Конфликт при ребейзе { $branch } на { $onto }: { $error }
+    Разрешите конфликты, выполните git rebase --continue и повторите gw stack rebase
+stack-rebased = Стек обновлен
+stack-mr-exists = MR для { $branch } уже существует: !{ $iid }
+stack-mr-retarget-hint = MR !{ $iid } направлен в { $target }, а не в { $expected }. Выполните gw stack retarget
+stack-rebase-hint = Выполните gw stack rebase, чтобы перенести ветки на новых родителей
+stack-no-merged-parents = Смерженных родителей в стеке нет
+stack-mr-retargeted = MR !{ $iid } ({ $branch }) перенаправлен в { $target }
+
+## Синхронизация
+
+sync-in-progress = Синхронизация уже выполняется. Используйте gw sync --continue или gw sync --abort
+sync-unresolved-conflicts = Остались неразрешенные конфликты: { $files }
+sync-aborted-partial = Уже синхронизированные ветки остались обновленными: { $branches }
+sync-aborted = Синхронизация отменена
+sync-merging = Мерж { $parent } в { $branch }
+sync-step-failed = Не удалось обновить { $branch } из { $parent }: { $error }
+sync-conflicts = Конфликты:
+sync-resolve-hint = Разрешите конфликты, добавьте файлы (git add) и выполните gw sync --continue. Для отмены: gw sync --abort
+sync-not-task-branch = Ветка { $branch } не является веткой фичи или задачи
+sync-task-without-epic = У задачи { $task } не указан эпик
+sync-tech-debt = Задачи техдолга ведутся от master, ветки фичи нет
+sync-updating = Обновляю { $branch }
+sync-using-remote = Локальная ветка { $branch } не обновлена, использую origin/{ $branch }
+sync-no-state = Нет прерванной синхронизации
+
+## Очистка веток
+
+cleanup-prune-failed = Не удалось обновить удаленные ветки: { $error }
+cleanup-protected-failed = Не удалось получить защищенные ветки: { $error }
+cleanup-checking = Проверяю { $count } веток
+cleanup-skipping = Пропускаю { $branch }: { $error }
+cleanup-nothing = Ветки для удаления не найдены
+cleanup-use-yes = Для удаления без подтверждения используйте --yes
+cleanup-confirm = Удалить { $count } веток?
+cleanup-deleted-local = Удалена локальная ветка { $branch }
+cleanup-delete-local-failed = Не удалось удалить локальную ветку { $branch }: { $error }
+cleanup-deleted-remote = Удалена ветка origin/{ $branch }
+cleanup-delete-remote-failed = Не удалось удалить ветку origin/{ $branch }: { $error }
+cleanup-reason-issue-closed = задача #{ $iid } закрыта
+cleanup-reason-epic-closed = эпик &{ $iid } закрыт
+cleanup-header-branch = Ветка
+cleanup-header-location = Где
+cleanup-header-reason = Причина
+
+## Учет времени
+
+time-timer-already-started = Таймер задачи { $task } уже запущен в { $time }
+time-timer-started = Таймер задачи { $task } запущен в { $time }
+time-timer-not-started = Таймер задачи { $task } не запущен
+time-too-short = Прошло меньше минуты, время на задачу { $task } не списано
+time-logged = На задачу #{ $iid } списано { $duration }. Всего: { $total }
+time-jira-unsupported = Списание времени в Jira не поддерживается. Время по задаче { $task }: { $duration }
+time-jira-timer-unsupported = Таймер для задач Jira не поддерживается: время по { $task } некуда списать
+time-no-epic = Без эпика
+time-week = Неделя { $start } — { $end }
+time-no-entries = Списаний времени нет
+time-total = Итого: { $total }
+
+## Коммиты и хуки
+
+commit-message-read-failed = Не удалось прочитать сообщение коммита: { $error }
+commit-message-write-failed = Не удалось записать сообщение коммита: { $error }
+commit-pattern-invalid = Некорректный шаблон сообщения коммита { $pattern }: { $error }
+commit-pattern-mismatch = Сообщение коммита "{ $subject }" не соответствует шаблону { $pattern }
+hooks-unsupported = Хук { $hook } не поддерживается. Доступны: { $available }
+hooks-skipped = Хук { $hook } пропущен: уже есть { $path }
+hooks-chaining = Существующий хук { $hook } будет вызываться из хука gw
+hooks-installed = Установлен хук { $hook }
+hooks-removed-restored = Хук { $hook } удален, исходный хук восстановлен
+hooks-removed = Хук { $hook } удален
+hooks-dir = Каталог хуков: { $path }
+hooks-state-installed = установлен
+hooks-state-foreign = чужой хук
+hooks-state-missing = не установлен
+hooks-state-disabled = выключен в конфиге
+hooks-state-chained = вызывает исходный хук
+hooks-protected-push = Push в защищенную ветку { $remote }/{ $branch } запрещен. Создайте MR: gw mr
+hooks-branch-name = Ветка { $branch } не соответствует шаблону именования (<задача>-task, feature/<эпик>)
+hooks-task = Задача { $task }: { $title } ({ $url })
+hooks-branch-pattern-invalid = Некорректный шаблон имени ветки { $pattern }: { $error }
+
+## Лог
+
+logs-dir-not-found = Не удалось найти каталог для логов
+logs-empty = Лог пока пуст: { $path }
+
+## Автодополнение
+
+completions-shell-unsupported = Оболочка { $shell } не поддерживается
+completions-refreshed = Кэш автодополнения обновлен: задач { $issues }, эпиков { $epics }
+
+## Документация
+
+help-gen-man-written = Man страниц: { $count }, каталог { $path }
+help-gen-markdown-written = Справка по командам: { $path }
+help-gen-markdown-title = Команды gw
+help-gen-aliases-section = ПСЕВДОНИМЫ
+
+## Панель
+
+ui-requires-terminal = Панель работает только в терминале с текстовым выводом
+ui-title = gw: ветка { $branch }
+ui-panel-issues = Мои задачи
+ui-panel-my-mrs = Мои MR
+ui-panel-reviews = Ждут моего ревью
+ui-panel-branches = Ветки задач
+ui-help = Tab панель · ↑↓ выбор · Enter переключиться · o открыть · m создать MR · r обновить · q выход
+ui-refreshing = Обновляю...
+ui-press-enter = Нажмите Enter, чтобы вернуться в панель
+ui-mr-exists = MR уже создан
+ui-opened = Открыто: { $url }
+ui-nothing-to-open = Нечего открыть
+
+## Открытие в браузере
+
+open-opening = Открываю { $url }
+open-copied = Скопировано: { $url }
+open-epic-not-found = У задачи { $task } нет эпика
+open-pipeline-not-found = У MR !{ $iid } нет пайплайна
+
+## Команды и аргументы
+
+arg-output = Формат вывода: текст, один JSON документ или JSON строки по мере выполнения
+arg-quiet = Выводить только ошибки и результат команды
+arg-verbose = Подробный вывод: -v отладочные сообщения и лог, -vv лог уровня debug
+arg-log-level = Уровень записей в файле лога. По умолчанию из GW_LOG, иначе info
+arg-dry-run = Показать планируемые операции git, API и записи файлов, не выполняя их. Не поддерживается в ui, open, completions --refresh, sync --continue и sync --abort
+cmd-init = Инициализировать проект
+cmd-global-config = Глобальная конфигурация
+cmd-checkout-task = Переключиться на ветку задачи
+arg-task-key = iid задачи GitLab или ключ задачи Jira (PROJ-123)
+cmd-checkout-feature = Переключиться на ветку фичи
+arg-feature-key = iid эпика GitLab или ключ эпика Jira (PROJ-123)
+cmd-tasks = Мои задачи в проекте с выбором задачи для переключения
+arg-milestone = Майлстоун
+arg-label = Метка (можно указать несколько раз)
+arg-tasks-state = Состояние задач
+arg-epic-iid = iid эпика
+cmd-done = Завершить задачу по настройкам workflow проекта
+arg-task-from-branch = Задача. По умолчанию определяется по текущей ветке
+cmd-task = Работа с задачами
+cmd-epic = Работа с эпиками
+cmd-merge-request = Создать MR или управлять MR
+cmd-stack = Стек зависимых веток задач
+cmd-sync = Обновить ветку фичи из базовой ветки и передать изменения в ветки задач
+arg-sync-strategy = Способ обновления. По умолчанию из конфига проекта
+arg-sync-continue = Продолжить после разрешения конфликтов
+arg-sync-abort = Отменить прерванную синхронизацию
+cmd-cleanup = Удалить ветки задач и фич со смерженными MR или закрытыми задачами
+arg-cleanup-local = Только локальные ветки
+arg-cleanup-yes = Удалить без подтверждения
+cmd-time = Учет времени по задачам
+cmd-commit = Коммит со ссылкой на задачу текущей ветки
+arg-commit-message = Сообщение коммита. Если не указано, открывается редактор
+arg-commit-type = Тип Conventional Commits. По умолчанию определяется по меткам задачи
+arg-commit-all = Добавить все измененные файлы (git commit -a)
+cmd-hooks = Git хуки проекта
+arg-mr-create-source = Исходная ветка
+arg-mr-create-target = Целевая ветка
+arg-mr-create-review = Добавить ревьюверов в MR
+arg-mr-create-template = Шаблон описания из .gitlab/merge_request_templates или путь к файлу. Без значения - выбрать из списка
+arg-mr-create-edit = Открыть описание в $EDITOR перед созданием MR
+arg-mr-create-draft = Создать MR как черновик (Draft)
+arg-mr-create-no-draft = Создать MR не черновиком, даже если черновик задан в настройках проекта
+arg-mr-create-labels = Метка MR (можно указать несколько раз)
+arg-mr-create-copy-labels = Скопировать метки из задачи
+arg-mr-create-squash = Squash коммитов при мерже
+arg-mr-create-no-squash = Не делать squash коммитов при мерже
+arg-mr-create-remove-source-branch = Удалить исходную ветку после мержа
+arg-mr-create-keep-source-branch = Не удалять исходную ветку после мержа
+arg-mr-create-allow-collaboration = Разрешить коммиты от участников целевого проекта (для форков)
+arg-mr-create-target-project = id целевого проекта, если MR создается из форка
+cmd-gc-set-token = Установить токен
+cmd-gc-set-host = Установить gitlab хост
+cmd-gc-set-jira = Настроить Jira как источник задач
+arg-gc-set-jira-host = Адрес Jira, например https://jira.example.com
+arg-gc-set-jira-token = Токен Jira
+arg-gc-set-jira-user = Email или логин пользователя (для Jira Cloud)
+arg-gc-set-jira-epic-link-field = Поле Epic Link, например customfield_10014
+cmd-gc-set-language = Язык сообщений и справки
+arg-gc-set-language-language = Язык
+cmd-mr-list = Список MR проекта
+arg-mr-list-state = Состояние MR
+arg-mr-list-all = Показать MR всех авторов, а не только мои
+cmd-mr-view = Информация о MR, апрувы и статус мержа
+arg-mr-iid = iid MR. По умолчанию MR текущей ветки
+cmd-mr-approve = Апрувнуть MR
+cmd-mr-merge = Смержить MR
+arg-mr-merge-when-pipeline-succeeds = Смержить после успешного пайплайна
+arg-mr-merge-squash = Squash коммитов
+arg-mr-merge-remove-source-branch = Удалить исходную ветку
+cmd-mr-rebase = Ребейз MR на целевую ветку средствами GitLab
+cmd-mr-close = Закрыть MR
+cmd-mr-reopen = Переоткрыть MR
+cmd-mr-comments = Неразрешенные обсуждения MR по файлам и строкам
+arg-mr-comments-all = Показать также разрешенные обсуждения и общие комментарии
+cmd-mr-reply = Ответить в обсуждение MR
+arg-discussion = id обсуждения
+arg-mr-reply-body = Текст ответа. Если не указан, открывается $EDITOR
+arg-mr-reply-resolve = Разрешить обсуждение после ответа
+cmd-mr-resolve = Разрешить обсуждение MR
+arg-mr-resolve-unresolve = Снова открыть обсуждение
+cmd-mr-note = Оставить общий комментарий в MR
+arg-mr-note-body = Текст комментария. Если не указан, открывается $EDITOR
+cmd-mr-checkout = Забрать исходную ветку MR локально (в том числе из форка)
+arg-mr-checkout-iid = iid MR
+cmd-stack-show = Показать цепочку веток текущего стека и их MR
+cmd-stack-new = Создать ветку задачи поверх текущей ветки
+cmd-stack-set-parent = Указать родительскую ветку
+arg-stack-set-parent-parent = Родительская ветка
+arg-stack-set-parent-branch = Ветка. По умолчанию текущая
+cmd-stack-rebase = Ребейз веток стека по порядку на своих родителей
+arg-stack-rebase-push = Отправить ветки в origin (--force-with-lease)
+cmd-stack-mr = Создать MR для каждой ветки стека в ее родителя
+arg-stack-mr-draft = Создать MR как черновики (Draft)
+cmd-stack-retarget = Перенаправить MR, чьи родители уже смержены
+cmd-time-start = Запустить таймер задачи
+cmd-time-stop = Остановить таймер и списать время на задачу
+cmd-time-log = Списать время на задачу вручную
+arg-time-log-duration = Длительность в формате GitLab, например 1h30m
+cmd-time-report = Отчет о списанном времени за неделю по задачам и эпикам
+arg-time-report-weeks-ago = Сколько недель назад. 0 - текущая неделя
+cmd-hooks-install = Установить хуки из конфига проекта. Существующие хуки вызываются перед хуками gw
+cmd-hooks-uninstall = Удалить хуки gw и вернуть исходные
+cmd-hooks-status = Состояние хуков
+cmd-task-new = Создать задачу и назначить ее на себя
+arg-task-new-title = Заголовок задачи
+arg-task-new-description = Описание задачи. Если не указано, открывается $EDITOR
+arg-task-new-weight = Вес задачи
+arg-task-new-epic = iid родительского эпика
+arg-task-new-checkout = Сразу переключиться на ветку задачи
+cmd-epic-tree = Дерево дочерних эпиков и задач
+arg-epic-tree-mine = Показать только мои задачи
+cmd-logs = Показать последние записи лога
+arg-logs-lines = Количество строк
+arg-logs-follow = Следить за новыми записями
+arg-logs-path = Только вывести путь к файлу лога
+cmd-completions = Скрипт автодополнения для bash, zsh, fish, elvish или PowerShell
+arg-completions-shell = Оболочка
+arg-completions-refresh = Обновить кэш задач и эпиков для автодополнения
+cmd-help-gen = Сгенерировать man страницы и markdown справку по командам
+arg-help-gen-out = Каталог для документации
+cmd-ui = Панель с задачами, MR и ветками в терминале
+cmd-open = Открыть в браузере задачу, эпик, MR, пайплайн или проект текущей ветки
+arg-open-target = Что открыть
+arg-open-print = Только вывести адрес
+arg-open-copy = Скопировать адрес в буфер обмена
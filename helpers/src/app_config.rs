@@ -4,11 +4,14 @@ use std::io::{BufWriter, Write};
 use std::path::PathBuf;
 use log::{info, warn};
 use crate::structs::AppConfig;
+use crate::{t, Printer};
 
 pub fn load_app_config() -> AppConfig {
     let default_config = AppConfig {
         gitlab_token: "".to_string(),
         gitlab_host: "gitlab.example.com".to_string(),
+        jira: None,
+        language: None,
     };
 
     let config_path = match get_app_config_file_path() {
@@ -29,13 +32,13 @@ pub fn load_app_config() -> AppConfig {
             match serde_json::from_reader(file) {
                 Ok(config) => config,
                 Err(e) => {
-                    warn!("Ошибка загрузки конфига. Использую конфиг по умолчанию: \n {}", e.to_string());
+                    warn!("Ошибка загрузки конфига. Использую конфиг по умолчанию: \n {}", e);
                     default_config
                 }
             }
         }
         Err(e) => {
-            warn!("Ошибка чтения файла конфига. Использую конфиг по умолчанию: \n {}", e.to_string());
+            warn!("Ошибка чтения файла конфига. Использую конфиг по умолчанию: \n {}", e);
             default_config
         }
     }
@@ -54,15 +57,22 @@ pub fn get_app_config_dir() -> Option<PathBuf> {
 }
 
 pub fn get_app_config_file_path() -> Option<PathBuf> {
-    match get_app_config_dir() {
-        Some(p) => Some(p.join("server.json")),
-        None => None,
+    get_app_config_dir().map(|p| p.join("server.json"))
+}
+
+/// Выводит запись глобального конфига в режиме --dry-run. Возвращает true, если записывать не нужно
+pub fn plan_app_config_save(dry_run: bool) -> bool {
+    if dry_run {
+        let path = get_app_config_file_path().map(|p| p.display().to_string()).unwrap_or_default();
+        Printer::print_plan(t!("dry-run-save-app-config", path = path), None);
     }
+
+    dry_run
 }
 
 pub fn save_app_config(config: AppConfig) -> Result<(), String> {
     let config_path = match get_app_config_file_path() {
-        None => {return Err("Ошибка открытия файла".to_string())}
+        None => {return Err(t!("config-file-open-failed"))}
         Some(path) => {path}
     };
 
@@ -0,0 +1,54 @@
+use std::fs;
+use std::path::PathBuf;
+use log::warn;
+use serde::{Deserialize, Serialize};
+use crate::{t, ProjectId};
+
+/// Значение для автодополнения с подсказкой
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CompletionItem {
+    pub value: String,
+    pub title: String,
+}
+
+/// Кэш автодополнения проекта. Дополнение вызывается на каждый TAB, поэтому API в нем не используется
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CompletionCache {
+    /// Открытые задачи, назначенные на меня
+    pub issues: Vec<CompletionItem>,
+    /// Эпики этих задач
+    pub epics: Vec<CompletionItem>,
+}
+
+pub fn load_completion_cache(project_id: ProjectId) -> CompletionCache {
+    let path = match get_completion_cache_path(project_id) {
+        Some(p) if p.exists() => p,
+        _ => return CompletionCache::default(),
+    };
+
+    match fs::read_to_string(&path).map(|content| serde_json::from_str(content.as_str())) {
+        Ok(Ok(cache)) => cache,
+        _ => {
+            warn!("Ошибка чтения кэша автодополнения {}", path.display());
+            CompletionCache::default()
+        }
+    }
+}
+
+pub fn save_completion_cache(project_id: ProjectId, cache: &CompletionCache) -> Result<(), String> {
+    let path = get_completion_cache_path(project_id).ok_or(t!("config-dir-not-found"))?;
+    let content = serde_json::to_string(cache).map_err(|err| err.to_string())?;
+
+    fs::write(path, content).map_err(|err| err.to_string())
+}
+
+/// $XDG_CACHE_HOME/awork/completions/<project_id>.json
+fn get_completion_cache_path(project_id: ProjectId) -> Option<PathBuf> {
+    let path = dirs::cache_dir()?.join("awork").join("completions");
+
+    if !path.exists() {
+        fs::create_dir_all(&path).ok()?;
+    }
+
+    Some(path.join(format!("{}.json", project_id)))
+}
@@ -0,0 +1,36 @@
+use std::fs;
+use std::process::Command;
+use log::error;
+use crate::structs::LogError;
+use crate::t;
+
+/// Открывает текст в редакторе из $VISUAL/$EDITOR и возвращает результат
+pub fn edit_text(initial: &str, file_name: &str) -> Result<String, String> {
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_string());
+
+    let path = std::env::temp_dir().join(format!("{}-{}", std::process::id(), file_name));
+    fs::write(&path, initial).log_error()?;
+
+    let mut parts = editor.split_whitespace();
+    let program = match parts.next() {
+        Some(p) => p,
+        None => return Err(t!("editor-not-set")),
+    };
+
+    let status = Command::new(program).args(parts).arg(&path).status();
+
+    let result = match status {
+        Ok(status) if status.success() => fs::read_to_string(&path).log_error(),
+        Ok(status) => Err(t!("editor-failed", status = status)),
+        Err(err) => {
+            error!("{:?}", err);
+            Err(t!("editor-launch-failed", editor = editor, error = err))
+        }
+    };
+
+    let _ = fs::remove_file(&path);
+
+    result
+}
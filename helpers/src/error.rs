@@ -0,0 +1,112 @@
+use std::fmt::{Debug, Display, Formatter};
+use log::error;
+use serde::Serialize;
+
+/// Код ошибки для скриптов (`--output json`)
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    /// Ошибка без более точного кода
+    CommandFailed,
+    InvalidArguments,
+    InvalidTaskKey,
+    InvalidFeatureKey,
+    NotFound,
+    DirtyRepository,
+    Conflict,
+    GitFailed,
+    ApiError,
+    Config,
+    Unsupported,
+    Cancelled,
+}
+
+impl ErrorCode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCode::CommandFailed => "command_failed",
+            ErrorCode::InvalidArguments => "invalid_arguments",
+            ErrorCode::InvalidTaskKey => "invalid_task_key",
+            ErrorCode::InvalidFeatureKey => "invalid_feature_key",
+            ErrorCode::NotFound => "not_found",
+            ErrorCode::DirtyRepository => "dirty_repository",
+            ErrorCode::Conflict => "conflict",
+            ErrorCode::GitFailed => "git_failed",
+            ErrorCode::ApiError => "api_error",
+            ErrorCode::Config => "config",
+            ErrorCode::Unsupported => "unsupported",
+            ErrorCode::Cancelled => "cancelled",
+        }
+    }
+}
+
+impl Display for ErrorCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Ошибка команды: текст для пользователя и код для скриптов.
+/// Строковые ошибки превращаются в `command_failed`
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppError {
+    pub code: ErrorCode,
+    pub message: String,
+}
+
+impl AppError {
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        AppError { code, message: message.into() }
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::NotFound, message)
+    }
+
+    pub fn dirty_repository(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::DirtyRepository, message)
+    }
+
+    pub fn api(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::ApiError, message)
+    }
+
+    pub fn git(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::GitFailed, message)
+    }
+}
+
+impl Display for AppError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.message.as_str())
+    }
+}
+
+impl From<String> for AppError {
+    fn from(message: String) -> Self {
+        Self::new(ErrorCode::CommandFailed, message)
+    }
+}
+
+impl From<AppError> for String {
+    fn from(err: AppError) -> Self {
+        err.message
+    }
+}
+
+/// Как `LogError`, но с кодом ошибки
+pub trait WithCode<T> {
+    fn with_code(self, code: ErrorCode) -> Result<T, AppError>;
+}
+
+impl<T, E> WithCode<T> for Result<T, E>
+where
+    E: Display + Debug,
+{
+    fn with_code(self, code: ErrorCode) -> Result<T, AppError> {
+        self.map_err(|err| {
+            error!("{:?}", err);
+            AppError::new(code, err.to_string())
+        })
+    }
+}
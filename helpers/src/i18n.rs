@@ -0,0 +1,128 @@
+use std::sync::OnceLock;
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::FluentResource;
+use log::warn;
+use serde::{Deserialize, Serialize};
+use unic_langid::LanguageIdentifier;
+
+pub use fluent_bundle::FluentArgs;
+
+const RU_CATALOG: &str = include_str!("../locales/ru.ftl");
+const EN_CATALOG: &str = include_str!("../locales/en.ftl");
+
+/// Язык сообщений и справки
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    #[default]
+    Ru,
+    En,
+}
+
+impl Language {
+    /// Язык по переменным окружения LC_ALL, LC_MESSAGES и LANG
+    pub fn from_env() -> Language {
+        let locale = ["LC_ALL", "LC_MESSAGES", "LANG"].iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|value| !value.is_empty())
+            .unwrap_or_default();
+
+        match locale.as_str() {
+            // Локаль не задана: оставляем язык по умолчанию
+            "" | "C" | "POSIX" => Language::default(),
+            l if l.starts_with("C.") || l.starts_with("ru") => Language::Ru,
+            _ => Language::En,
+        }
+    }
+
+    fn get_catalog(&self) -> &'static str {
+        match self {
+            Language::Ru => RU_CATALOG,
+            Language::En => EN_CATALOG,
+        }
+    }
+
+    fn get_id(&self) -> &'static str {
+        match self {
+            Language::Ru => "ru",
+            Language::En => "en",
+        }
+    }
+}
+
+static LANGUAGE: OnceLock<Language> = OnceLock::new();
+
+/// Каталоги в порядке поиска сообщения: выбранный язык, затем русский
+static BUNDLES: OnceLock<Vec<FluentBundle<FluentResource>>> = OnceLock::new();
+
+/// Выбирает язык: из конфига, иначе по локали. Вызывается до разбора аргументов, чтобы справка была на нужном языке
+pub fn init_language(language: Option<Language>) {
+    let _ = LANGUAGE.set(language.unwrap_or_else(Language::from_env));
+}
+
+pub fn get_language() -> Language {
+    LANGUAGE.get().copied().unwrap_or_default()
+}
+
+/// Сообщение из каталога. Если сообщения нет, возвращается его id
+pub fn tr(id: &str, args: Option<&FluentArgs>) -> String {
+    let bundles = BUNDLES.get_or_init(|| {
+        let mut languages = vec![get_language()];
+        if get_language() != Language::Ru {
+            languages.push(Language::Ru);
+        }
+
+        languages.iter().map(build_bundle).collect()
+    });
+
+    for bundle in bundles {
+        let Some(pattern) = bundle.get_message(id).and_then(|m| m.value()) else {
+            continue;
+        };
+
+        let mut errors = vec![];
+        let text = bundle.format_pattern(pattern, args, &mut errors);
+        if !errors.is_empty() {
+            warn!("Ошибки форматирования сообщения {}: {:?}", id, errors);
+        }
+
+        return text.into_owned();
+    }
+
+    warn!("Сообщение {} не найдено в каталоге", id);
+    id.to_string()
+}
+
+fn build_bundle(language: &Language) -> FluentBundle<FluentResource> {
+    let lang_id: LanguageIdentifier = language.get_id().parse().unwrap_or_default();
+    let mut bundle = FluentBundle::new_concurrent(vec![lang_id]);
+    // Без символов изоляции вокруг подстановок, иначе они попадают в терминал и JSON
+    bundle.set_use_isolating(false);
+
+    let resource = match FluentResource::try_new(language.get_catalog().to_string()) {
+        Ok(resource) => resource,
+        Err((resource, errors)) => {
+            warn!("Ошибки разбора каталога {}: {:?}", language.get_id(), errors);
+            resource
+        }
+    };
+
+    if let Err(errors) = bundle.add_resource(resource) {
+        warn!("Повторяющиеся сообщения в каталоге {}: {:?}", language.get_id(), errors);
+    }
+
+    bundle
+}
+
+/// Сообщение из каталога с подстановками: `t!("id")`, `t!("id", name = value)`
+#[macro_export]
+macro_rules! t {
+    ($id:literal) => {
+        $crate::tr($id, None)
+    };
+    ($id:literal, $($name:ident = $value:expr),+ $(,)?) => {{
+        let mut args = $crate::FluentArgs::new();
+        $(args.set(stringify!($name), ($value).to_string());)+
+        $crate::tr($id, Some(&args))
+    }};
+}
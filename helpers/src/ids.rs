@@ -0,0 +1,42 @@
+use std::fmt::{Display, Formatter};
+use std::num::ParseIntError;
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+
+/// Идентификаторы GitLab. id глобальные, iid нумеруются внутри проекта или группы,
+/// поэтому у каждого свой тип и перепутать их при вызове API нельзя
+macro_rules! gitlab_id {
+    ($($(#[$meta:meta])* $name:ident),+ $(,)?) => {$(
+        $(#[$meta])*
+        #[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[serde(transparent)]
+        pub struct $name(pub u64);
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseIntError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                s.parse().map(Self)
+            }
+        }
+    )+};
+}
+
+gitlab_id!(
+    /// id проекта
+    ProjectId,
+    /// id группы
+    GroupId,
+    /// iid задачи в проекте
+    IssueIid,
+    /// iid эпика в группе
+    EpicIid,
+    /// iid MR в проекте
+    MrIid,
+);
@@ -1,9 +1,23 @@
 mod app_config;
+mod completions;
+mod editor;
+mod error;
+mod i18n;
+mod ids;
+mod logs;
 mod printer;
 mod project_config;
 mod structs;
+mod timers;
 
 pub use app_config::*;
+pub use completions::*;
+pub use editor::*;
+pub use error::*;
+pub use i18n::*;
+pub use ids::*;
+pub use logs::*;
 pub use printer::*;
 pub use project_config::*;
 pub use structs::*;
+pub use timers::*;
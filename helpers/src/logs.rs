@@ -0,0 +1,47 @@
+use std::fs;
+use std::fs::{File, OpenOptions};
+use std::path::{Path, PathBuf};
+
+const LOG_FILE_NAME: &str = "gw.log";
+
+/// При достижении этого размера лог переименовывается в gw.log.1
+const MAX_LOG_SIZE: u64 = 5 * 1024 * 1024;
+
+/// Сколько старых логов хранить: gw.log.1 ... gw.log.3
+const MAX_ROTATED_LOGS: usize = 3;
+
+/// Каталог логов: $XDG_STATE_HOME/awork (~/.local/state/awork), если его нет - каталог кэша
+pub fn get_app_log_dir() -> Option<PathBuf> {
+    let path = dirs::state_dir().or_else(dirs::cache_dir)?.join("awork");
+
+    if !path.exists() {
+        fs::create_dir_all(&path).ok()?;
+    }
+
+    Some(path)
+}
+
+pub fn get_log_file_path() -> Option<PathBuf> {
+    get_app_log_dir().map(|p| p.join(LOG_FILE_NAME))
+}
+
+/// Открывает лог на дозапись. Слишком большой лог предварительно ротируется
+pub fn open_log_file() -> Option<File> {
+    let path = get_log_file_path()?;
+
+    if fs::metadata(&path).is_ok_and(|m| m.len() >= MAX_LOG_SIZE) {
+        rotate_logs(&path);
+    }
+
+    OpenOptions::new().create(true).append(true).open(&path).ok()
+}
+
+fn rotate_logs(path: &Path) {
+    let rotated = |index: usize| PathBuf::from(format!("{}.{}", path.display(), index));
+
+    for index in (1..MAX_ROTATED_LOGS).rev() {
+        let _ = fs::rename(rotated(index), rotated(index + 1));
+    }
+
+    let _ = fs::rename(path, rotated(1));
+}
@@ -1,32 +1,322 @@
-use colored::Colorize;
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+use colored::{ColoredString, Colorize};
+use serde::Serialize;
+use serde_json::{json, Value};
+use crate::error::ErrorCode;
 
-pub struct Printer {}
+/// Формат вывода: цветной текст или структурированный результат для скриптов и редакторов
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    /// Один JSON документ в конце выполнения команды
+    Json,
+    /// Каждое сообщение и результат отдельной JSON строкой
+    Ndjson,
+}
+
+/// Подробность вывода: -q, по умолчанию, -v, -vv
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub enum Verbosity {
+    /// Только ошибки и результат команды
+    Quiet,
+    #[default]
+    Normal,
+    /// Отладочные сообщения и записи лога уровня info
+    Verbose,
+    /// Записи лога уровня debug
+    Trace,
+}
+
+impl Verbosity {
+    pub fn from_flags(quiet: bool, verbose: u8) -> Self {
+        match (quiet, verbose) {
+            (true, _) => Verbosity::Quiet,
+            (false, 0) => Verbosity::Normal,
+            (false, 1) => Verbosity::Verbose,
+            _ => Verbosity::Trace,
+        }
+    }
+}
+
+/// Текущий принтер процесса. Устанавливается в main после разбора аргументов
+static PRINTER: OnceLock<Printer> = OnceLock::new();
+
+static FAILED: AtomicBool = AtomicBool::new(false);
+
+/// Накопленный вывод для формата json
+static DOCUMENT: Mutex<JsonDocument> = Mutex::new(JsonDocument { results: vec![], messages: vec![], error: None });
+
+struct JsonDocument {
+    results: Vec<Value>,
+    messages: Vec<Value>,
+    error: Option<Value>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Printer {
+    format: OutputFormat,
+    verbosity: Verbosity,
+    stdout_color: bool,
+    stderr_color: bool,
+}
 
 impl Printer {
-    pub fn print(message: String, module_name: Option<String>) {
-        let message_text =match module_name {
-            None => {message}
-            Some(mn) => {
-                format!("{}{}", mn.on_cyan().white(), message)
+    /// Цвет включается для терминала, если не задан NO_COLOR. CLICOLOR_FORCE включает цвет всегда
+    pub fn new(format: OutputFormat, verbosity: Verbosity) -> Self {
+        let force = std::env::var("CLICOLOR_FORCE").is_ok_and(|v| !v.is_empty() && v != "0");
+        let no_color = std::env::var("NO_COLOR").is_ok_and(|v| !v.is_empty());
+        let color = |is_terminal: bool| force || (!no_color && is_terminal);
+
+        // Решение о цвете принимается для каждого потока отдельно в paint
+        colored::control::set_override(true);
+
+        Printer {
+            format,
+            verbosity,
+            stdout_color: color(std::io::stdout().is_terminal()),
+            stderr_color: color(std::io::stderr().is_terminal()),
+        }
+    }
+
+    /// Делает принтер текущим. Повторная установка игнорируется
+    pub fn install(self) {
+        let _ = PRINTER.set(self);
+    }
+
+    pub fn current() -> &'static Printer {
+        PRINTER.get_or_init(|| Printer::new(OutputFormat::Text, Verbosity::Normal))
+    }
+
+    pub fn format(&self) -> OutputFormat {
+        self.format
+    }
+
+    pub fn verbosity(&self) -> Verbosity {
+        self.verbosity
+    }
+
+    pub fn use_color(&self, stderr: bool) -> bool {
+        if stderr { self.stderr_color } else { self.stdout_color }
+    }
+
+    pub fn message(&self, message: String, module_name: Option<String>) {
+        if self.is_structured_format() {
+            self.emit_message("message", message, module_name);
+            return;
+        }
+
+        println!("{}", self.with_module(message, module_name, false));
+    }
+
+    pub fn error(&self, text: String, module_name: Option<String>) {
+        if self.is_structured_format() {
+            self.emit_message("error", text, module_name);
+            return;
+        }
+
+        let text = format!("{} {}", self.paint(" ERROR ".on_red().white().bold(), true), text);
+        eprintln!("{}", self.with_module(text, module_name, true));
+    }
+
+    pub fn warning(&self, text: String, module_name: Option<String>) {
+        if self.verbosity < Verbosity::Normal {
+            return;
+        }
+        if self.is_structured_format() {
+            self.emit_message("warning", text, module_name);
+            return;
+        }
+
+        let text = format!("{} {}", self.paint(" WARN ".on_yellow().white().bold(), true), text);
+        eprintln!("{}", self.with_module(text, module_name, true));
+    }
+
+    pub fn info(&self, text: String, module_name: Option<String>) {
+        if self.verbosity < Verbosity::Normal {
+            return;
+        }
+        if self.is_structured_format() {
+            self.emit_message("info", text, module_name);
+            return;
+        }
+
+        let text = format!("{} {}", self.paint(" INFO ".on_blue().white().bold(), false), text);
+        println!("{}", self.with_module(text, module_name, false));
+    }
+
+    pub fn success(&self, text: String, module_name: Option<String>) {
+        if self.verbosity < Verbosity::Normal {
+            return;
+        }
+        if self.is_structured_format() {
+            self.emit_message("success", text, module_name);
+            return;
+        }
+
+        let text = self.paint(text.green().bold(), false);
+        println!("{}", self.with_module(text, module_name, false));
+    }
+
+    /// Отладочное сообщение, видно с -v. Идет в stderr, чтобы не смешиваться с выводом команды
+    pub fn debug(&self, text: String, module_name: Option<String>) {
+        if self.verbosity < Verbosity::Verbose {
+            return;
+        }
+        if self.is_structured_format() {
+            self.emit_message("debug", text, module_name);
+            return;
+        }
+
+        let text = format!("{} {}", self.paint(" DEBUG ".on_bright_black().white(), true), text);
+        eprintln!("{}", self.with_module(text, module_name, true));
+    }
+
+    /// Операция, которая была бы выполнена без --dry-run. Видна и с -q: в этом режиме это и есть вывод команды
+    pub fn plan(&self, text: String, module_name: Option<String>) {
+        if self.is_structured_format() {
+            self.emit_message("plan", text, module_name);
+            return;
+        }
+
+        let text = format!("{} {}", self.paint(" DRY-RUN ".on_magenta().white().bold(), false), text);
+        println!("{}", self.with_module(text, module_name, false));
+    }
+
+    /// Структурированный результат команды. В текстовом режиме результат уже выведен сообщениями
+    pub fn result<T: Serialize>(&self, result: &T) {
+        let value = match serde_json::to_value(result) {
+            Ok(v) => v,
+            Err(err) => {
+                self.error(err.to_string(), None);
+                return;
             }
         };
 
-        println!("{}", message_text);
+        match self.format {
+            OutputFormat::Text => {}
+            OutputFormat::Json => Self::with_document(|doc| doc.results.push(value)),
+            OutputFormat::Ndjson => println!("{}", json!({ "type": "result", "data": value })),
+        }
+    }
+
+    /// Ошибка, завершившая команду, с кодом для скриптов
+    pub fn failure(&self, code: ErrorCode, text: String) {
+        FAILED.store(true, Ordering::Relaxed);
+
+        match self.format {
+            OutputFormat::Text => self.error(text, None),
+            OutputFormat::Json => Self::with_document(|doc| doc.error = Some(json!({ "code": code, "message": text }))),
+            OutputFormat::Ndjson => println!("{}", json!({ "type": "error", "code": code, "message": text })),
+        }
+    }
+
+    /// Завершает вывод. Возвращает false, если команда завершилась ошибкой
+    pub fn complete(&self) -> bool {
+        let ok = !FAILED.load(Ordering::Relaxed);
+
+        if self.format != OutputFormat::Json {
+            return ok;
+        }
+
+        Self::with_document(|doc| {
+            // Одна команда обычно возвращает один результат
+            let result = match doc.results.len() {
+                0 => Value::Null,
+                1 => doc.results.remove(0),
+                _ => Value::Array(std::mem::take(&mut doc.results)),
+            };
+
+            println!("{}", json!({
+                "ok": ok,
+                "result": result,
+                "error": doc.error,
+                "messages": doc.messages,
+            }));
+        });
+
+        ok
+    }
+
+    fn is_structured_format(&self) -> bool {
+        self.format != OutputFormat::Text
+    }
+
+    fn with_module(&self, text: String, module_name: Option<String>, stderr: bool) -> String {
+        match module_name {
+            None => text,
+            Some(mn) => format!("{}{}", self.paint(mn.on_cyan().white(), stderr), text),
+        }
+    }
+
+    fn paint(&self, text: ColoredString, stderr: bool) -> String {
+        if self.use_color(stderr) { text.to_string() } else { text.input }
+    }
+
+    fn emit_message(&self, level: &str, text: String, module_name: Option<String>) {
+        let message = json!({ "type": level, "module": module_name, "message": text });
+
+        match self.format {
+            OutputFormat::Ndjson => println!("{}", message),
+            _ => Self::with_document(|doc| doc.messages.push(message)),
+        }
+    }
+
+    fn with_document<R>(f: impl FnOnce(&mut JsonDocument) -> R) -> R {
+        let mut doc = DOCUMENT.lock().unwrap_or_else(|err| err.into_inner());
+        f(&mut doc)
+    }
+}
+
+/// Вывод через текущий принтер, чтобы не передавать его через все обработчики и менеджеры
+impl Printer {
+    pub fn get_output_format() -> OutputFormat {
+        Self::current().format
+    }
+
+    pub fn is_structured() -> bool {
+        Self::current().is_structured_format()
+    }
+
+    pub fn print(message: String, module_name: Option<String>) {
+        Self::current().message(message, module_name);
     }
 
     pub fn print_error(text: String, module_name: Option<String>) {
-        Self::print(format!("{} {}", " ERROR ".on_red().white().bold(), text), module_name);
+        Self::current().error(text, module_name);
     }
 
     pub fn print_warning(text: String, module_name: Option<String>) {
-        Self::print(format!("{} {}", " WARN ".on_yellow().white().bold(), text), module_name);
+        Self::current().warning(text, module_name);
     }
 
     pub fn print_info(text: String, module_name: Option<String>) {
-        Self::print(format!("{} {}", " INFO ".on_blue().white().bold(), text), module_name);
+        Self::current().info(text, module_name);
     }
 
     pub fn print_success(text: String, module_name: Option<String>) {
-        Self::print(format!("{}", text.green().bold()), module_name);
+        Self::current().success(text, module_name);
+    }
+
+    pub fn print_debug(text: String, module_name: Option<String>) {
+        Self::current().debug(text, module_name);
+    }
+
+    pub fn print_plan(text: String, module_name: Option<String>) {
+        Self::current().plan(text, module_name);
+    }
+
+    pub fn print_result<T: Serialize>(result: &T) {
+        Self::current().result(result);
+    }
+
+    pub fn print_failure(code: ErrorCode, text: String) {
+        Self::current().failure(code, text);
+    }
+
+    pub fn finish() -> bool {
+        Self::current().complete()
     }
-}
\ No newline at end of file
+}
@@ -1,7 +1,8 @@
 use std::fs::File;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use log::error;
 use crate::structs::ProjectConfig;
+use crate::t;
 
 pub fn load_project_config(dir: PathBuf) -> Result<ProjectConfig, String> {
     let config_path = get_project_config_file_path(&dir);
@@ -12,18 +13,18 @@ pub fn load_project_config(dir: PathBuf) -> Result<ProjectConfig, String> {
                 Ok(config) => Ok(config),
                 Err(e) => {
                     error!("{:?}", e);
-                    Err("Не удалось прочитать конфиг проекта.".to_string())
+                    Err(t!("project-config-read-failed"))
                 }
             }
         }
         Err(e) => {
             error!("{:?}", e);
-            Err("Не удалось открыть конфиг проекта.\n Попробуйте выполнить команду init".to_string())
+            Err(t!("project-config-open-failed"))
         }
     }
 }
 
 
-pub fn get_project_config_file_path(dir: &PathBuf) -> PathBuf {
+pub fn get_project_config_file_path(dir: &Path) -> PathBuf {
     dir.join(".aworkcli")
 }
@@ -1,26 +1,217 @@
+use std::collections::BTreeMap;
 use std::fmt::{Debug, Display};
 use std::fs::File;
 use std::io::{BufWriter, Write};
-use std::path::PathBuf;
+use std::path::Path;
 use log::error;
 use serde::{Deserialize, Serialize};
+use crate::i18n::Language;
+use crate::ids::{GroupId, ProjectId};
 use crate::project_config::get_project_config_file_path;
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct AppConfig {
     pub gitlab_token: String,
     pub gitlab_host: String,
+    #[serde(default)]
+    pub jira: Option<JiraConfig>,
+    /// Язык сообщений. Если не указан, определяется по LANG
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<Language>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct JiraConfig {
+    /// Адрес Jira вместе со схемой, например https://jira.example.com
+    pub host: String,
+    /// Email (Jira Cloud) или логин. Если пустой, токен передается как Bearer
+    #[serde(default)]
+    pub user: String,
+    pub token: String,
+    /// Поле со ссылкой на эпик (Epic Link), например customfield_10014
+    #[serde(default)]
+    pub epic_link_field: Option<String>,
 }
 
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ProjectConfig {
-    pub project_id: u64,
-    pub group_id: u64,
+    pub project_id: ProjectId,
+    pub group_id: GroupId,
+    #[serde(default)]
+    pub workflow: WorkflowConfig,
+    /// Путь к шаблону описания MR относительно корня репозитория
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mr_template: Option<String>,
+    #[serde(default)]
+    pub merge_request: MrDefaults,
+    #[serde(default)]
+    pub sync: SyncConfig,
+    #[serde(default)]
+    pub commit: CommitConfig,
+    #[serde(default)]
+    pub hooks: HooksConfig,
+}
+
+/// Git хуки, устанавливаемые gw hooks install
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct HooksConfig {
+    #[serde(default = "default_hooks")]
+    pub enabled: Vec<String>,
+    /// Ветки, push в которые блокирует pre-push
+    #[serde(default = "default_protected_branches")]
+    pub protected_branches: Vec<String>,
+    /// Регулярное выражение для имен веток. По умолчанию <key>-task и feature/<key>
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch_pattern: Option<String>,
+    /// Показывать задачу при ручном переключении ветки (не из gw)
+    #[serde(default = "default_true")]
+    pub show_issue: bool,
+}
+
+impl Default for HooksConfig {
+    fn default() -> Self {
+        HooksConfig {
+            enabled: default_hooks(),
+            protected_branches: default_protected_branches(),
+            branch_pattern: None,
+            show_issue: true,
+        }
+    }
+}
+
+fn default_hooks() -> Vec<String> {
+    ["pre-push", "prepare-commit-msg", "commit-msg", "post-checkout"].iter().map(|h| h.to_string()).collect()
+}
+
+fn default_protected_branches() -> Vec<String> {
+    vec!["master".to_string()]
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Сообщения коммитов: ссылка на задачу, тип Conventional Commits и проверка по шаблону
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct CommitConfig {
+    #[serde(default)]
+    pub reference: ReferencePosition,
+    /// Метка задачи -> тип коммита (feat, fix, ...)
+    #[serde(default = "default_commit_types")]
+    pub types: BTreeMap<String, String>,
+    /// Регулярное выражение для проверки первой строки в хуке commit-msg
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+}
+
+impl Default for CommitConfig {
+    fn default() -> Self {
+        CommitConfig { reference: Default::default(), types: default_commit_types(), pattern: None }
+    }
+}
+
+/// Где ставить ссылку на задачу: `feat: #12 текст` (без типа `[#12] текст`, чтобы git не принял строку за комментарий) или `feat: текст (#12)`
+#[derive(Debug, Deserialize, Serialize, Default, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ReferencePosition {
+    #[default]
+    Prefix,
+    Suffix,
+}
+
+fn default_commit_types() -> BTreeMap<String, String> {
+    BTreeMap::from([
+        ("тип::баг".to_string(), "fix".to_string()),
+        ("тип::фича".to_string(), "feat".to_string()),
+        ("тип::техдолг".to_string(), "refactor".to_string()),
+        ("тип::документация".to_string(), "docs".to_string()),
+    ])
+}
+
+/// Синхронизация веток фич с базовой веткой (gw sync)
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SyncConfig {
+    #[serde(default = "default_base_branch")]
+    pub base_branch: String,
+    #[serde(default)]
+    pub strategy: SyncStrategy,
+}
+
+impl Default for SyncConfig {
+    fn default() -> Self {
+        SyncConfig { base_branch: default_base_branch(), strategy: Default::default() }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum SyncStrategy {
+    #[default]
+    Merge,
+    Rebase,
+}
+
+fn default_base_branch() -> String {
+    "master".to_string()
+}
+
+/// Параметры MR по умолчанию. Флаги команды mr имеют приоритет
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct MrDefaults {
+    #[serde(default)]
+    pub draft: bool,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// Копировать метки из задачи
+    #[serde(default)]
+    pub copy_issue_labels: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub milestone: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub squash: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remove_source_branch: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_collaboration: Option<bool>,
+    /// id целевого проекта для MR из форка
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_project_id: Option<ProjectId>,
+}
+
+/// Изменения задачи на шагах работы: cot, mr и done
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct WorkflowConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub in_progress: Option<WorkflowStep>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub review: Option<WorkflowStep>,
+    /// Если не указан, задача закрывается
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub done: Option<WorkflowStep>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct WorkflowStep {
+    #[serde(default)]
+    pub add_labels: Vec<String>,
+    #[serde(default)]
+    pub remove_labels: Vec<String>,
+    /// Назначить задачу на текущего пользователя
+    #[serde(default)]
+    pub assign_me: bool,
+    /// Закрыть задачу
+    #[serde(default)]
+    pub close: bool,
 }
 
 impl ProjectConfig {
-    pub fn save(&self, dir: &PathBuf) -> Result<(), String> {
+    /// Проект, в котором живут MR: целевой проект для форков или сам проект
+    pub fn get_mr_project_id(&self) -> ProjectId {
+        self.merge_request.target_project_id.unwrap_or(self.project_id)
+    }
+
+    pub fn save(&self, dir: &Path) -> Result<(), String> {
         let config_path = get_project_config_file_path(dir);
 
         let file = match File::create(config_path) {
@@ -0,0 +1,33 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use log::warn;
+use crate::app_config::get_app_config_dir;
+use crate::t;
+
+/// Запущенные таймеры: ключ задачи -> время старта (unix, секунды)
+pub fn load_timers() -> HashMap<String, i64> {
+    let path = match get_timers_file_path() {
+        Some(p) if p.exists() => p,
+        _ => return HashMap::new(),
+    };
+
+    match fs::read_to_string(&path).map(|content| serde_json::from_str(content.as_str())) {
+        Ok(Ok(timers)) => timers,
+        _ => {
+            warn!("Ошибка чтения таймеров {}", path.display());
+            HashMap::new()
+        }
+    }
+}
+
+pub fn save_timers(timers: &HashMap<String, i64>) -> Result<(), String> {
+    let path = get_timers_file_path().ok_or(t!("config-dir-not-found"))?;
+    let content = serde_json::to_string_pretty(timers).map_err(|err| err.to_string())?;
+
+    fs::write(path, content).map_err(|err| err.to_string())
+}
+
+fn get_timers_file_path() -> Option<PathBuf> {
+    get_app_config_dir().map(|p| p.join("timers.json"))
+}
@@ -3,45 +3,72 @@ use std::path::PathBuf;
 use std::process::Command;
 use git2::{BranchType, Repository};
 use log::error;
-use helpers::Printer;
+use serde::Serialize;
+use helpers::{t, AppError, ErrorCode, Printer, WithCode};
+
+#[derive(Serialize, Debug, Clone)]
+pub struct GitCommit {
+    pub sha: String,
+    pub title: String,
+}
+
+/// Переменная окружения, которую gw выставляет своим вызовам git, чтобы хуки не повторяли его работу
+pub const GW_INTERNAL_ENV: &str = "GW_INTERNAL";
 
 pub struct GitManager {
     repository: Repository,
     dir: PathBuf,
+    /// Изменяющие операции только выводятся, чтение репозитория выполняется как обычно
+    dry_run: bool,
 }
 
 impl GitManager {
-    pub fn new(path: &PathBuf) -> Result<Self, String> {
-        let repo = Repository::open(path).map_err(|err| err.to_string())?;
+    pub fn new(path: &PathBuf, dry_run: bool) -> Result<Self, AppError> {
+        let repo = Repository::open(path).with_code(ErrorCode::Config)?;
 
-        Ok(GitManager { repository: repo, dir: path.to_path_buf() })
+        Ok(GitManager { repository: repo, dir: path.to_path_buf(), dry_run })
     }
 
-    pub fn get_repo_url(&self) -> Result<String, String> {
-        let remote = self.repository.find_remote("origin").map_err(|err| err.to_string())?;
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    pub fn get_repo_url(&self) -> Result<String, AppError> {
+        let remote = self.repository.find_remote("origin").with_code(ErrorCode::GitFailed)?;
         match remote.url() {
             None => {
-                return Err("Не удалось найти ссылку на репозиторий".to_string())
+                Err(AppError::not_found(t!("git-remote-url-not-found")))
             }
             Some(url) => Ok(url.to_string())
         }
     }
 
-    pub fn pull(&self) -> Result<(), String> {
-        match Command::new("git").current_dir(&self.dir).arg("pull").status() {
-            Ok(_) => Ok(()),
+    pub fn pull(&self) -> Result<(), AppError> {
+        if self.plan(&["pull"]) {
+            return Ok(());
+        }
+
+        // Вывод git идет в stderr, чтобы не смешиваться с результатом команды (--output json)
+        match self.git_command().arg("pull").stdout(std::io::stderr()).status() {
+            Ok(status) if status.success() => Ok(()),
+            Ok(_) => Err(AppError::git(t!("git-pull-failed"))),
             Err(err) => {
                 error!("{:?}", err);
-                Err(err.to_string())
+                Err(AppError::git(err.to_string()))
             }
         }
     }
 
-    pub fn get_current_branch(&self) -> Result<String, String> {
-        Ok(self.repository.head().unwrap().name().unwrap().to_string().split("/").last().unwrap().to_string())
+    pub fn get_current_branch(&self) -> Result<String, AppError> {
+        let head = self.repository.head().with_code(ErrorCode::GitFailed)?;
+
+        match head.shorthand() {
+            Some(name) => Ok(name.to_string()),
+            None => Err(AppError::git(t!("git-current-branch-unknown"))),
+        }
     }
 
-    pub fn checkout(&self, target_branch: String, parent_branch: Option<String>) -> Result<(), String> {
+    pub fn checkout(&self, target_branch: String, parent_branch: Option<String>) -> Result<(), AppError> {
         self.pull()?;
         match self.get_existed_branch(target_branch.to_string()) {
             Ok(branch) => {
@@ -51,7 +78,7 @@ impl GitManager {
             Err(err) => {
                 match parent_branch {
                     Some(branch) => {
-                        Printer::print_warning(format!("Ветка {} не найдена. Переключаюсь на родительскую ({}) и создаю целевую ветку...", target_branch, branch), Some("Git".to_string()));
+                        Printer::print_warning(t!("git-branch-not-found-creating", branch = target_branch, parent = branch), Some("Git".to_string()));
 
                         let _parent = self.get_existed_branch(branch.to_string())?;
                         self.raw_checkout(_parent, false)?;
@@ -68,27 +95,42 @@ impl GitManager {
         Ok(())
     }
 
-    pub fn raw_checkout(&self, branch: String, create: bool) -> Result<(), String> {
-        let mut command = Command::new("git");
-        command.current_dir(&self.dir).arg("checkout");
+    /// remote - имя удаленного репозитория или его адрес
+    pub fn pull_from(&self, remote: &str, refspec: &str) -> Result<(), AppError> {
+        self.git_run(&["pull", remote, refspec])
+    }
+
+    /// remote - имя удаленного репозитория или его адрес
+    pub fn fetch(&self, remote: &str, refspec: &str) -> Result<(), AppError> {
+        self.git_run(&["fetch", remote, refspec])
+    }
+
+    pub fn raw_checkout(&self, branch: String, create: bool) -> Result<(), AppError> {
+        let mut args = vec!["checkout"];
 
         if create {
-            command.arg("-b");
+            args.push("-b");
+        }
+        args.push(branch.as_str());
+
+        if self.plan(&args) {
+            return Ok(());
         }
-        command.arg(branch);
 
-        match command.status() {
-            Ok(_) => Ok(()),
+        // Следующие шаги (merge, rebase) нельзя выполнять на ветке, с которой не удалось уйти
+        match self.git_command().args(&args).stdout(std::io::stderr()).status() {
+            Ok(status) if status.success() => Ok(()),
+            Ok(_) => Err(AppError::git(t!("git-checkout-failed", branch = branch))),
             Err(err) => {
                 error!("{:?}", err);
-                Err(err.to_string())
+                Err(AppError::git(err.to_string()))
             }
         }
     }
 
 
-    pub fn is_dirty(&self) -> Result<bool, String> {
-        match Command::new("git").current_dir(&self.dir).arg("status").arg("-s").output() {
+    pub fn is_dirty(&self) -> Result<bool, AppError> {
+        match self.git_command().arg("status").arg("-s").output() {
             Ok(output) => {
                 if output.stdout.is_empty() {
                     return Ok(false);
@@ -104,12 +146,12 @@ impl GitManager {
             }
             Err(err) => {
                 error!("{:?}", err);
-                Err(err.to_string())
+                Err(AppError::git(err.to_string()))
             }
         }
     }
 
-    pub fn get_existed_branch(&self, branch: String) -> Result<String, String> {
+    pub fn get_existed_branch(&self, branch: String) -> Result<String, AppError> {
         if self.repository.find_branch(branch.as_str(), BranchType::Local).is_ok() {
             return Ok(branch);
         };
@@ -120,6 +162,211 @@ impl GitManager {
             return Ok(remote_branch);
         }
 
-        Err(format!("Ну удалось найти ветку {} (или {})", branch, remote_branch))
+        Err(AppError::not_found(t!("git-branch-not-found", branch = branch, remote = remote_branch)))
+    }
+
+    /// Полный sha коммита, на который указывает ссылка
+    pub fn get_commit_sha(&self, reference: &str) -> Result<String, AppError> {
+        let sha = self.git_output(&["rev-parse", "--verify", format!("{}^{{commit}}", reference).as_str()])?;
+
+        Ok(sha.trim().to_string())
+    }
+
+    /// Коммиты ветки head, которых нет в base
+    pub fn get_commits(&self, base: &str, head: &str) -> Result<Vec<GitCommit>, AppError> {
+        let output = self.git_output(&["log", "--format=%h%x09%s", format!("{}..{}", base, head).as_str()])?;
+
+        Ok(output.lines().filter_map(|line| {
+            line.split_once('\t').map(|(sha, title)| GitCommit { sha: sha.to_string(), title: title.to_string() })
+        }).collect())
+    }
+
+    /// Файлы, измененные в head относительно общей с base точки
+    pub fn get_changed_files(&self, base: &str, head: &str) -> Result<Vec<String>, AppError> {
+        let output = self.git_output(&["diff", "--name-only", format!("{}...{}", base, head).as_str()])?;
+
+        Ok(output.lines().map(|l| l.to_string()).collect())
+    }
+
+    /// Ребейз ветки на родителя с учетом истории родителя (`--fork-point`)
+    pub fn rebase(&self, onto: &str, branch: &str) -> Result<(), AppError> {
+        self.git_run(&["rebase", "--fork-point", onto, branch])
+    }
+
+    pub fn push(&self, branch: &str, force: bool) -> Result<(), AppError> {
+        if force {
+            self.git_run(&["push", "--force-with-lease", "-u", "origin", branch])
+        } else {
+            self.git_run(&["push", "-u", "origin", branch])
+        }
+    }
+
+    /// Имена веток. Для удаленных веток без префикса origin/
+    pub fn get_branches(&self, remote: bool) -> Result<Vec<String>, AppError> {
+        let branch_type = if remote { BranchType::Remote } else { BranchType::Local };
+        let branches = self.repository.branches(Some(branch_type)).with_code(ErrorCode::GitFailed)?;
+
+        let mut names = vec![];
+        for branch in branches {
+            let (branch, _) = branch.with_code(ErrorCode::GitFailed)?;
+            let name = match branch.name() {
+                Ok(Some(name)) => name.to_string(),
+                _ => continue,
+            };
+
+            if !remote {
+                names.push(name);
+            } else if let Some(name) = name.strip_prefix("origin/")
+                && name != "HEAD" {
+                names.push(name.to_string());
+            }
+        }
+
+        Ok(names)
+    }
+
+    pub fn delete_branch(&self, branch: &str, remote: bool) -> Result<(), AppError> {
+        if remote {
+            self.git_run(&["push", "origin", "--delete", branch])
+        } else {
+            // -d не удаляет ветку с коммитами, которых нет ни в HEAD, ни в ее upstream
+            self.git_run(&["branch", "-d", branch])
+        }
+    }
+
+    /// Обновляет удаленные ветки, удаляя ссылки на уже удаленные
+    pub fn prune(&self) -> Result<(), AppError> {
+        self.git_run(&["fetch", "--prune", "origin"])
+    }
+
+    pub fn merge(&self, branch: &str) -> Result<(), AppError> {
+        self.git_run(&["merge", "--no-edit", branch])
+    }
+
+    /// Файлы с неразрешенными конфликтами
+    pub fn get_conflicted_files(&self) -> Result<Vec<String>, AppError> {
+        let output = self.git_output(&["diff", "--name-only", "--diff-filter=U"])?;
+
+        Ok(output.lines().map(|l| l.to_string()).collect())
+    }
+
+    /// Завершает прерванный конфликтом merge или rebase
+    pub fn continue_operation(&self, rebase: bool) -> Result<(), AppError> {
+        if rebase {
+            self.git_run(&["-c", "core.editor=true", "rebase", "--continue"])?;
+        } else if self.repository.path().join("MERGE_HEAD").exists() {
+            self.git_run(&["commit", "--no-edit"])?;
+        }
+
+        Ok(())
+    }
+
+    pub fn abort_operation(&self, rebase: bool) -> Result<(), AppError> {
+        if rebase {
+            self.git_run(&["rebase", "--abort"])?;
+        } else if self.repository.path().join("MERGE_HEAD").exists() {
+            self.git_run(&["merge", "--abort"])?;
+        }
+
+        Ok(())
+    }
+
+    /// Коммит с сообщением. С edit сообщение открывается в редакторе
+    pub fn commit(&self, message: &str, all: bool, edit: bool) -> Result<(), AppError> {
+        let mut args = vec!["commit"];
+
+        if all {
+            args.push("-a");
+        }
+        if edit {
+            args.push("-e");
+        }
+        args.extend(["-m", message]);
+
+        if self.plan(&args) {
+            return Ok(());
+        }
+
+        match self.git_command().args(&args).status() {
+            Ok(status) if status.success() => Ok(()),
+            Ok(_) => Err(AppError::git(t!("git-commit-failed"))),
+            Err(err) => {
+                error!("{:?}", err);
+                Err(AppError::git(err.to_string()))
+            }
+        }
+    }
+
+    /// Каталог хуков с учетом core.hooksPath
+    pub fn get_hooks_dir(&self) -> Result<PathBuf, AppError> {
+        let path = self.git_output(&["rev-parse", "--git-path", "hooks"])?;
+
+        Ok(self.dir.join(path.trim()))
+    }
+
+    /// Каталог .git репозитория
+    pub fn get_git_dir(&self) -> PathBuf {
+        self.repository.path().to_path_buf()
+    }
+
+    pub fn get_config(&self, key: &str) -> Option<String> {
+        self.git_output(&["config", "--get", key]).ok().map(|v| v.trim().to_string())
+    }
+
+    pub fn set_config(&self, key: &str, value: &str) -> Result<(), AppError> {
+        self.git_run(&["config", key, value])
+    }
+
+    /// Пары ключ-значение локального конфига, ключи которых подходят под регулярное выражение
+    pub fn get_config_values(&self, pattern: &str) -> Result<Vec<(String, String)>, AppError> {
+        // git config возвращает код 1, если ничего не найдено
+        let output = self.git_output(&["config", "--get-regexp", pattern]).unwrap_or_default();
+
+        Ok(output.lines().filter_map(|line| {
+            line.split_once(' ').map(|(key, value)| (key.to_string(), value.to_string()))
+        }).collect())
+    }
+
+    /// Выводит операцию вместо выполнения в режиме --dry-run. Возвращает true, если операцию выполнять не нужно
+    fn plan(&self, args: &[&str]) -> bool {
+        if self.dry_run {
+            Printer::print_plan(format!("git {}", args.join(" ")), None);
+        }
+
+        self.dry_run
+    }
+
+    fn git_command(&self) -> Command {
+        let mut command = Command::new("git");
+        command.current_dir(&self.dir).env(GW_INTERNAL_ENV, "1");
+        command
+    }
+
+    /// Изменяющая репозиторий команда git
+    fn git_run(&self, args: &[&str]) -> Result<(), AppError> {
+        if self.plan(args) {
+            return Ok(());
+        }
+
+        self.git_output(args)?;
+
+        Ok(())
     }
-}
\ No newline at end of file
+
+    fn git_output(&self, args: &[&str]) -> Result<String, AppError> {
+        Printer::print_debug(format!("git {}", args.join(" ")), None);
+
+        match self.git_command().args(args).output() {
+            Ok(output) if output.status.success() => Ok(String::from_utf8_lossy(&output.stdout).to_string()),
+            Ok(output) => {
+                let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+                error!("git {:?}: {}", args, stderr);
+                Err(AppError::git(stderr))
+            }
+            Err(err) => {
+                error!("{:?}", err);
+                Err(AppError::git(err.to_string()))
+            }
+        }
+    }
+}
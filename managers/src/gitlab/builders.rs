@@ -1,12 +1,13 @@
 use std::borrow::Cow;
 use derive_builder::Builder;
-use gitlab::api::Endpoint;
+use helpers::{EpicIid, GroupId, IssueIid, MrIid, ProjectId};
+use gitlab::api::{BodyError, Endpoint, FormParams, Pageable, QueryParams};
 use gitlab::api::endpoint_prelude::Method;
 
 #[derive(Debug, Builder, Clone)]
 pub struct EpicApi {
-    group_id: u64,
-    iid: u16
+    group_id: GroupId,
+    iid: EpicIid
 }
 
 impl EpicApi {
@@ -24,4 +25,189 @@ impl Endpoint for EpicApi {
     fn endpoint(&self) -> Cow<'static, str> {
         format!("groups/{}/epics/{}", self.group_id, self.iid).into()
     }
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Builder, Clone)]
+pub struct EpicEpicsApi {
+    group_id: GroupId,
+    iid: EpicIid
+}
+
+impl EpicEpicsApi {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EpicEpicsApiBuilder {
+        EpicEpicsApiBuilder::default()
+    }
+}
+
+impl Endpoint for EpicEpicsApi {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/epics/{}/epics", self.group_id, self.iid).into()
+    }
+}
+
+impl Pageable for EpicEpicsApi {}
+
+
+#[derive(Debug, Builder, Clone)]
+pub struct EpicIssuesApi {
+    group_id: GroupId,
+    iid: EpicIid
+}
+
+impl EpicIssuesApi {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EpicIssuesApiBuilder {
+        EpicIssuesApiBuilder::default()
+    }
+}
+
+impl Endpoint for EpicIssuesApi {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/epics/{}/issues", self.group_id, self.iid).into()
+    }
+}
+
+impl Pageable for EpicIssuesApi {}
+
+
+#[derive(Debug, Builder, Clone)]
+pub struct ProjectMilestonesApi {
+    project_id: ProjectId,
+    #[builder(setter(into))]
+    title: String,
+}
+
+impl ProjectMilestonesApi {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectMilestonesApiBuilder {
+        ProjectMilestonesApiBuilder::default()
+    }
+}
+
+impl Endpoint for ProjectMilestonesApi {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/milestones", self.project_id).into()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push("title", self.title.as_str())
+            .push("include_ancestors", true);
+
+        params
+    }
+}
+
+
+#[derive(Debug, Builder, Clone)]
+pub struct DiscussionNoteApi {
+    project_id: ProjectId,
+    mr_iid: MrIid,
+    #[builder(setter(into))]
+    discussion_id: String,
+    #[builder(setter(into))]
+    body: String,
+}
+
+impl DiscussionNoteApi {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DiscussionNoteApiBuilder {
+        DiscussionNoteApiBuilder::default()
+    }
+}
+
+impl Endpoint for DiscussionNoteApi {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/merge_requests/{}/discussions/{}/notes", self.project_id, self.mr_iid, self.discussion_id).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+        params.push("body", self.body.as_str());
+
+        params.into_body()
+    }
+}
+
+
+#[derive(Debug, Builder, Clone)]
+pub struct ResolveDiscussionApi {
+    project_id: ProjectId,
+    mr_iid: MrIid,
+    #[builder(setter(into))]
+    discussion_id: String,
+    resolved: bool,
+}
+
+impl ResolveDiscussionApi {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ResolveDiscussionApiBuilder {
+        ResolveDiscussionApiBuilder::default()
+    }
+}
+
+impl Endpoint for ResolveDiscussionApi {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/merge_requests/{}/discussions/{}", self.project_id, self.mr_iid, self.discussion_id).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+        params.push("resolved", self.resolved);
+
+        params.into_body()
+    }
+}
+
+#[derive(Debug, Builder, Clone)]
+pub struct IssueSpentTimeApi {
+    project_id: ProjectId,
+    issue_iid: IssueIid,
+    /// Длительность в формате GitLab: 1h30m
+    #[builder(setter(into))]
+    duration: String,
+}
+impl IssueSpentTimeApi {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> IssueSpentTimeApiBuilder {
+        IssueSpentTimeApiBuilder::default()
+    }
+}
+impl Endpoint for IssueSpentTimeApi {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/issues/{}/add_spent_time", self.project_id, self.issue_iid).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+        params.push("duration", self.duration.as_str());
+
+        params.into_body()
+    }
+}
@@ -0,0 +1,113 @@
+use std::fmt::Debug;
+use std::time::Instant;
+use bytes::Bytes;
+use gitlab::api::{ApiError, Client, RestClient};
+use gitlab::{Gitlab, GitlabError, RestError};
+use graphql_client::{GraphQLQuery, QueryBody};
+use http::request::Builder as RequestBuilder;
+use http::Response;
+use log::{debug, log_enabled, trace, Level};
+use serde::Deserialize;
+use url::Url;
+
+/// Тела запросов и ответов длиннее этого обрезаются в логе
+const MAX_LOGGED_BODY: usize = 4096;
+
+const SECRET_HEADERS: [&str; 3] = ["private-token", "authorization", "job-token"];
+
+/// Клиент GitLab, который пишет в лог запросы и ответы API. Токен в логе заменяется на [REDACTED]
+#[derive(Clone)]
+pub struct TracingClient {
+    inner: Gitlab,
+    token: String,
+}
+
+impl TracingClient {
+    pub fn new(inner: Gitlab, token: String) -> Self {
+        Self { inner, token }
+    }
+
+    pub fn graphql<Q>(&self, query: &QueryBody<Q::Variables>) -> Result<Q::ResponseData, GitlabError>
+    where
+        Q: GraphQLQuery,
+        Q::Variables: Debug,
+        for<'d> Q::ResponseData: Deserialize<'d>,
+    {
+        let started = Instant::now();
+        let result = self.inner.graphql::<Q>(query);
+
+        match &result {
+            Ok(_) => debug!("GraphQL {} ok ({} ms)", query.operation_name, started.elapsed().as_millis()),
+            Err(err) => debug!("GraphQL {} failed ({} ms): {}", query.operation_name, started.elapsed().as_millis(), self.redact(err.to_string())),
+        }
+
+        result
+    }
+
+    fn redact(&self, text: String) -> String {
+        if self.token.is_empty() {
+            return text;
+        }
+
+        text.replace(self.token.as_str(), "[REDACTED]")
+    }
+
+    fn format_body(&self, body: &[u8]) -> String {
+        let text = String::from_utf8_lossy(body);
+        let text = match text.char_indices().nth(MAX_LOGGED_BODY) {
+            Some((index, _)) => format!("{}... ({} bytes)", &text[..index], body.len()),
+            None => text.to_string(),
+        };
+
+        self.redact(text)
+    }
+}
+
+impl RestClient for TracingClient {
+    type Error = RestError;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        self.inner.rest_endpoint(endpoint)
+    }
+
+    fn instance_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        self.inner.instance_endpoint(endpoint)
+    }
+}
+
+impl Client for TracingClient {
+    fn rest(&self, request: RequestBuilder, body: Vec<u8>) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        let method = request.method_ref().map(|m| m.to_string()).unwrap_or_default();
+        let uri = self.redact(request.uri_ref().map(|u| u.to_string()).unwrap_or_default());
+
+        if log_enabled!(Level::Trace) {
+            if let Some(headers) = request.headers_ref() {
+                for (name, value) in headers {
+                    let value = if SECRET_HEADERS.contains(&name.as_str()) {
+                        "[REDACTED]".to_string()
+                    } else {
+                        self.redact(String::from_utf8_lossy(value.as_bytes()).to_string())
+                    };
+                    trace!("> {}: {}", name, value);
+                }
+            }
+            if !body.is_empty() {
+                trace!("> {}", self.format_body(&body));
+            }
+        }
+
+        let started = Instant::now();
+        let result = self.inner.rest(request, body);
+        let elapsed = started.elapsed().as_millis();
+
+        match &result {
+            Ok(response) => {
+                debug!("{} {} -> {} ({} ms)", method, uri, response.status(), elapsed);
+                trace!("< {}", self.format_body(response.body()));
+            }
+            Err(err) => debug!("{} {} failed ({} ms): {}", method, uri, elapsed, self.redact(err.to_string())),
+        }
+
+        result
+    }
+}
@@ -0,0 +1,61 @@
+use graphql_client::{GraphQLQuery, QueryBody};
+use serde::{Deserialize, Serialize};
+use crate::gitlab::structs::GlTimelog;
+
+/// Списания времени пользователя в проекте за период. В REST API такого списка нет
+pub struct TimelogsQuery;
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelogsVariables {
+    pub project_id: String,
+    pub username: String,
+    pub start_date: String,
+    pub end_date: String,
+    pub after: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct TimelogsData {
+    pub timelogs: TimelogsConnection,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelogsConnection {
+    pub page_info: PageInfo,
+    pub nodes: Vec<GlTimelog>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PageInfo {
+    pub has_next_page: bool,
+    pub end_cursor: Option<String>,
+}
+
+const TIMELOGS_QUERY: &str = r#"
+query timelogs($projectId: ProjectID, $username: String, $startDate: Time, $endDate: Time, $after: String) {
+  timelogs(projectId: $projectId, username: $username, startDate: $startDate, endDate: $endDate, first: 100, after: $after) {
+    pageInfo { hasNextPage endCursor }
+    nodes {
+      spentAt
+      timeSpent
+      issue { iid title webUrl epic { iid title } }
+    }
+  }
+}
+"#;
+
+impl GraphQLQuery for TimelogsQuery {
+    type Variables = TimelogsVariables;
+    type ResponseData = TimelogsData;
+
+    fn build_query(variables: Self::Variables) -> QueryBody<Self::Variables> {
+        QueryBody {
+            variables,
+            query: TIMELOGS_QUERY,
+            operation_name: "timelogs",
+        }
+    }
+}
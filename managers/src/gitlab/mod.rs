@@ -1,75 +1,176 @@
 mod builders;
-mod structs;
+mod client;
+mod graphql;
+pub mod structs;
+mod tracker;
+
+pub use client::TracingClient;
+pub use tracker::GitlabTracker;
 
 use gitlab::{Gitlab};
-use gitlab::api::{groups, projects, users, Query};
+use gitlab::api::{groups, ignore, issues, paged, projects, users, ApiError, Pagination, Query};
 use log::error;
-use helpers::LogError;
-use crate::gitlab::builders::EpicApi;
-use crate::gitlab::structs::{GlEpic, GlGroup, GlIssue, GlMergeRequest, GlProject, GlUser};
+use helpers::{t, AppError, EpicIid, ErrorCode, GroupId, IssueIid, MrIid, ProjectId, WithCode};
+use crate::gitlab::builders::{DiscussionNoteApi, EpicApi, EpicEpicsApi, EpicIssuesApi, IssueSpentTimeApi, ProjectMilestonesApi, ResolveDiscussionApi};
+use graphql_client::GraphQLQuery;
+use crate::gitlab::graphql::{TimelogsQuery, TimelogsVariables};
+use crate::gitlab::structs::{GlApprovals, GlDiff, GlDiscussion, GlEpic, GlEpicTree, GlGroup, GlIssue, GlIssueState, GlIssuesFilter, GlIssueUpdate, GlMergeOptions, GlMergeRequest, GlMergeRequestOptions, GlMergeRequestState, GlMergeRequestsFilter, GlMilestone, GlNewIssue, GlNote, GlProject, GlProtectedBranch, GlTimelog, GlTimeStats, GlUser};
+
+/// Ошибки запросов к API: 404 отдельно, чтобы отличать отсутствующие задачи и MR от сбоев
+trait ApiResult<T> {
+    fn api_error(self) -> Result<T, AppError>;
+}
+
+impl<T, E> ApiResult<T> for Result<T, ApiError<E>>
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    fn api_error(self) -> Result<T, AppError> {
+        self.map_err(|err| {
+            error!("{:?}", err);
+            let code = match &err {
+                ApiError::GitlabWithStatus { status, .. }
+                | ApiError::GitlabObjectWithStatus { status, .. }
+                | ApiError::GitlabUnrecognizedWithStatus { status, .. } if *status == http::StatusCode::NOT_FOUND => ErrorCode::NotFound,
+                _ => ErrorCode::ApiError,
+            };
+            AppError::new(code, err.to_string())
+        })
+    }
+}
 
 #[derive(Clone)]
 pub struct GitlabManager {
-    pub client: Gitlab,
+    pub client: TracingClient,
 }
 
 // High level
 impl GitlabManager {
-    pub fn new(token: String, host: String) -> Result<Self, String> {
+    pub fn new(token: String, host: String) -> Result<Self, AppError> {
         if token.is_empty() {
-            return Err("Не указан токен".to_string()); // TODO Добавить команду установки токена
+            return Err(AppError::new(ErrorCode::Config, t!("gitlab-token-not-set")));
         }
 
-        let client = Gitlab::builder(host, token).cert_insecure().build();
+        let client = Gitlab::builder(host, token.to_string()).cert_insecure().build();
 
         match client {
-            Ok(cl) => { Ok(Self { client: cl }) }
+            Ok(cl) => { Ok(Self { client: TracingClient::new(cl, token) }) }
             Err(err) => {
                 error!("{:?}", err);
-                Err(err.to_string())
+                Err(AppError::api(err.to_string()))
             }
         }
     }
 
-    pub fn get_issue(&self, task: u64, project_id: u64) -> Result<GlIssue, String> {
-        let issue_url = match projects::issues::Issue::builder()
-            .project(project_id)
-            .issue(task)
-            .build() {
-            Ok(url) => url,
-            Err(err) => {
-                error!("{:?}", err);
-                return Err(err.to_string());
-            }
-        };
+    pub fn get_issue(&self, task: IssueIid, project_id: ProjectId) -> Result<GlIssue, AppError> {
+        let issue_url = projects::issues::Issue::builder()
+            .project(project_id.0)
+            .issue(task.0)
+            .build().with_code(ErrorCode::ApiError)?;
 
-        let issue: GlIssue = match issue_url.query(&self.client) {
-            Ok(i) => i,
-            Err(err) => {
-                error!("{:?}", err);
-                return Err(err.to_string());
-            }
-        };
+        let issue: GlIssue = issue_url.query(&self.client).api_error()?;
 
         Ok(issue)
     }
 
-    pub fn get_parent_epic(&self, epic_iid: u16, group_id: u64) -> Result<GlEpic, String> {
-        let epic_url = match EpicApi::builder().group_id(group_id).iid(epic_iid).build() {
-            Ok(url) => url,
-            Err(err) => {
-                error!("{:?}", err);
-                return Err(err.to_string());
-            }
-        };
+    pub fn get_issues(&self, project_id: ProjectId, filter: &GlIssuesFilter) -> Result<Vec<GlIssue>, AppError> {
+        let mut builder = projects::issues::Issues::builder();
+        builder.project(project_id.0);
 
-        let epic: GlEpic = match epic_url.query(&self.client) {
-            Ok(epic) => epic,
-            Err(err) => {
-                error!("{:?}", err);
-                return Err(err.to_string());
-            }
-        };
+        if let Some(assignee_id) = filter.assignee_id {
+            builder.assignee_id(assignee_id);
+        }
+        if let Some(milestone) = &filter.milestone {
+            builder.milestone_id(issues::IssueMilestone::Named(milestone.to_string().into()));
+        }
+        if !filter.labels.is_empty() {
+            builder.labels(filter.labels.iter().map(|l| l.as_str()));
+        }
+        match filter.state {
+            Some(GlIssueState::Opened) => { builder.state(issues::IssueState::Opened); }
+            Some(GlIssueState::Closed) => { builder.state(issues::IssueState::Closed); }
+            Some(GlIssueState::All) | None => {}
+        }
+        if let Some(epic_id) = filter.epic_id {
+            builder.epic_id(issues::IssueEpic::Id(epic_id));
+        }
+
+        let issues_url = builder.build().with_code(ErrorCode::ApiError)?;
+        let issues: Vec<GlIssue> = paged(issues_url, Pagination::All).query(&self.client).api_error()?;
+
+        Ok(issues)
+    }
+
+    pub fn create_issue(&self, project_id: ProjectId, new_issue: GlNewIssue) -> Result<GlIssue, AppError> {
+        let mut builder = projects::issues::CreateIssue::builder();
+        builder
+            .project(project_id.0)
+            .title(new_issue.title)
+            .description(new_issue.description)
+            .labels(new_issue.labels);
+
+        if let Some(milestone_id) = new_issue.milestone_id {
+            builder.milestone_id(milestone_id);
+        }
+        if let Some(weight) = new_issue.weight {
+            builder.weight(weight);
+        }
+        if let Some(epic_id) = new_issue.epic_id {
+            builder.epic_id(epic_id);
+        }
+        if let Some(assignee_id) = new_issue.assignee_id {
+            builder.assignee_id(assignee_id);
+        }
+
+        let request = builder.build().with_code(ErrorCode::ApiError)?;
+        let issue: GlIssue = request.query(&self.client).api_error()?;
+
+        Ok(issue)
+    }
+
+    pub fn update_issue(&self, project_id: ProjectId, issue_iid: IssueIid, update: GlIssueUpdate) -> Result<GlIssue, AppError> {
+        let mut builder = projects::issues::EditIssue::builder();
+        builder.project(project_id.0).issue(issue_iid.0);
+
+        for label in update.add_labels {
+            builder.add_label(label);
+        }
+        for label in update.remove_labels {
+            builder.remove_label(label);
+        }
+        if let Some(assignee_ids) = update.assignee_ids {
+            builder.assignee_ids(assignee_ids.into_iter());
+        }
+        if update.close {
+            builder.state_event(projects::issues::IssueStateEvent::Close);
+        }
+
+        let request = builder.build().with_code(ErrorCode::ApiError)?;
+        let issue: GlIssue = request.query(&self.client).api_error()?;
+
+        Ok(issue)
+    }
+
+    pub fn get_milestone(&self, project_id: ProjectId, title: String) -> Result<GlMilestone, AppError> {
+        let milestones_url = ProjectMilestonesApi::builder().project_id(project_id).title(title.to_string()).build().with_code(ErrorCode::ApiError)?;
+        let milestones: Vec<GlMilestone> = milestones_url.query(&self.client).api_error()?;
+
+        match milestones.into_iter().next() {
+            Some(milestone) => Ok(milestone),
+            None => Err(AppError::not_found(t!("gitlab-milestone-not-found", milestone = title))),
+        }
+    }
+
+    pub fn get_epic(&self, epic_iid: EpicIid, group_id: GroupId) -> Result<GlEpic, AppError> {
+        let epic_url = EpicApi::builder().group_id(group_id).iid(epic_iid).build().with_code(ErrorCode::ApiError)?;
+
+        let epic: GlEpic = epic_url.query(&self.client).api_error()?;
+
+        Ok(epic)
+    }
+
+    pub fn get_parent_epic(&self, epic_iid: EpicIid, group_id: GroupId) -> Result<GlEpic, AppError> {
+        let epic = self.get_epic(epic_iid, group_id)?;
 
         match epic.parent_iid {
             Some(iid) => {
@@ -81,43 +182,72 @@ impl GitlabManager {
         }
     }
 
-    pub fn get_current_user(&self) -> Result<GlUser, String> {
-        let url = match users::CurrentUser::builder().build() {
-            Ok(u) => u,
-            Err(err) => {
-                error!("{:?}", err);
-                return Err(err.to_string());
-            }
-        };
+    pub fn get_epic_tree(&self, epic_iid: EpicIid, group_id: GroupId) -> Result<GlEpicTree, AppError> {
+        let epic = self.get_epic(epic_iid, group_id)?;
 
-        let user: GlUser = match url.query(&self.client) {
-            Ok(u) => u,
-            Err(err) => {
-                error!("{:?}", err);
-                return Err(err.to_string());
-            }
-        };
+        let epics_url = EpicEpicsApi::builder().group_id(group_id).iid(epic_iid).build().with_code(ErrorCode::ApiError)?;
+        let child_epics: Vec<GlEpic> = paged(epics_url, Pagination::All).query(&self.client).api_error()?;
+
+        let issues_url = EpicIssuesApi::builder().group_id(group_id).iid(epic_iid).build().with_code(ErrorCode::ApiError)?;
+        let issues: Vec<GlIssue> = paged(issues_url, Pagination::All).query(&self.client).api_error()?;
+
+        let mut epics = vec![];
+        for child in child_epics {
+            epics.push(self.get_epic_tree(child.iid, group_id)?);
+        }
+
+        Ok(GlEpicTree { epic, epics, issues })
+    }
+
+    pub fn get_current_user(&self) -> Result<GlUser, AppError> {
+        let url = users::CurrentUser::builder().build().with_code(ErrorCode::ApiError)?;
+
+        let user: GlUser = url.query(&self.client).api_error()?;
 
         Ok(user)
     }
 
-    pub fn create_mr(&self, source_branch: String, target_branch: String, project_id: u64, title: Option<String>, description: Option<String>,) -> Result<GlMergeRequest, String> {
+    pub fn create_mr(&self, source_branch: String, target_branch: String, project_id: ProjectId, title: Option<String>, description: Option<String>, options: GlMergeRequestOptions) -> Result<GlMergeRequest, AppError> {
         let current_user = self.get_current_user()?;
 
         let description = description.unwrap_or_else(|| { "".to_string() });
-        let title = title.unwrap_or_else(|| { target_branch.to_string() });
+        let mut title = title.unwrap_or_else(|| { target_branch.to_string() });
 
-        let request = projects::merge_requests::CreateMergeRequest
-        ::builder()
-            .project(project_id)
+        if options.draft && !title.starts_with("Draft:") {
+            title = format!("Draft: {}", title);
+        }
+
+        let mut builder = projects::merge_requests::CreateMergeRequest::builder();
+        builder
+            .project(project_id.0)
             .source_branch(source_branch)
             .target_branch(target_branch.to_string())
             .title(title)
             .description(description)
-            .assignee(current_user.id)
-            .build().log_error()?;
+            .assignee(current_user.id);
 
-        let mr: GlMergeRequest = request.query(&self.client).log_error()?;
+        if !options.labels.is_empty() {
+            builder.labels(options.labels.into_iter());
+        }
+        if let Some(milestone_id) = options.milestone_id {
+            builder.milestone_id(milestone_id);
+        }
+        if let Some(squash) = options.squash {
+            builder.squash(squash);
+        }
+        if let Some(remove_source_branch) = options.remove_source_branch {
+            builder.remove_source_branch(remove_source_branch);
+        }
+        if let Some(allow_collaboration) = options.allow_collaboration {
+            builder.allow_collaboration(allow_collaboration);
+        }
+        if let Some(target_project_id) = options.target_project_id {
+            builder.target_project_id(target_project_id.0);
+        }
+
+        let request = builder.build().with_code(ErrorCode::ApiError)?;
+
+        let mr: GlMergeRequest = request.query(&self.client).api_error()?;
 
         Ok(mr)
     }
@@ -125,16 +255,255 @@ impl GitlabManager {
 
 
 impl GitlabManager {
-    pub fn get_group(&self, group: String) -> Result<GlGroup, String> {
-        let group_url = groups::Group::builder().group(group).build().map_err(|e| e.to_string())?;
-        let group: GlGroup = group_url.query(&self.client).map_err(|e| e.to_string())?;
+    pub fn get_group(&self, group: String) -> Result<GlGroup, AppError> {
+        let group_url = groups::Group::builder().group(group).build().with_code(ErrorCode::ApiError)?;
+        let group: GlGroup = group_url.query(&self.client).api_error()?;
 
         Ok(group)
     }
-    pub fn get_project(&self, project: String) -> Result<GlProject, String> {
-        let project_url = projects::Project::builder().project(project).build().map_err(|e| e.to_string())?;
-        let project: GlProject = project_url.query(&self.client).map_err(|e| e.to_string())?;
+    pub fn get_project(&self, project: String) -> Result<GlProject, AppError> {
+        let project_url = projects::Project::builder().project(project).build().with_code(ErrorCode::ApiError)?;
+        let project: GlProject = project_url.query(&self.client).api_error()?;
 
         Ok(project)
     }
+
+    pub fn get_protected_branches(&self, project_id: ProjectId) -> Result<Vec<GlProtectedBranch>, AppError> {
+        let branches_url = projects::protected_branches::ProtectedBranches::builder()
+            .project(project_id.0)
+            .build().with_code(ErrorCode::ApiError)?;
+
+        let branches: Vec<GlProtectedBranch> = paged(branches_url, Pagination::All).query(&self.client).api_error()?;
+
+        Ok(branches)
+    }
+}
+
+
+// Merge requests
+impl GitlabManager {
+    pub fn get_mrs(&self, project_id: ProjectId, filter: &GlMergeRequestsFilter) -> Result<Vec<GlMergeRequest>, AppError> {
+        let mut builder = projects::merge_requests::MergeRequests::builder();
+        builder.project(project_id.0);
+
+        if let Some(source_branch) = &filter.source_branch {
+            builder.source_branch(source_branch.as_str());
+        }
+        if let Some(target_branch) = &filter.target_branch {
+            builder.target_branch(target_branch.as_str());
+        }
+        if let Some(author_id) = filter.author_id {
+            builder.author(author_id);
+        }
+        if let Some(reviewer_id) = filter.reviewer_id {
+            builder.reviewer(reviewer_id);
+        }
+        match filter.state {
+            Some(GlMergeRequestState::Opened) => { builder.state(projects::merge_requests::MergeRequestState::Opened); }
+            Some(GlMergeRequestState::Closed) => { builder.state(projects::merge_requests::MergeRequestState::Closed); }
+            Some(GlMergeRequestState::Merged) => { builder.state(projects::merge_requests::MergeRequestState::Merged); }
+            Some(GlMergeRequestState::All) | None => {}
+        }
+
+        let mrs_url = builder.build().with_code(ErrorCode::ApiError)?;
+        let mrs: Vec<GlMergeRequest> = paged(mrs_url, Pagination::All).query(&self.client).api_error()?;
+
+        Ok(mrs)
+    }
+
+    pub fn get_mr(&self, project_id: ProjectId, mr_iid: MrIid) -> Result<GlMergeRequest, AppError> {
+        let mr_url = projects::merge_requests::MergeRequest::builder()
+            .project(project_id.0)
+            .merge_request(mr_iid.0)
+            .build().with_code(ErrorCode::ApiError)?;
+
+        let mr: GlMergeRequest = mr_url.query(&self.client).api_error()?;
+
+        Ok(mr)
+    }
+
+    pub fn get_mr_approvals(&self, project_id: ProjectId, mr_iid: MrIid) -> Result<GlApprovals, AppError> {
+        let approvals_url = projects::merge_requests::approvals::MergeRequestApprovals::builder()
+            .project(project_id.0)
+            .merge_request(mr_iid.0)
+            .build().with_code(ErrorCode::ApiError)?;
+
+        let approvals: GlApprovals = approvals_url.query(&self.client).api_error()?;
+
+        Ok(approvals)
+    }
+
+    pub fn approve_mr(&self, project_id: ProjectId, mr_iid: MrIid) -> Result<(), AppError> {
+        let request = projects::merge_requests::ApproveMergeRequest::builder()
+            .project(project_id.0)
+            .merge_request(mr_iid.0)
+            .build().with_code(ErrorCode::ApiError)?;
+
+        ignore(request).query(&self.client).api_error()?;
+
+        Ok(())
+    }
+
+    pub fn merge_mr(&self, project_id: ProjectId, mr_iid: MrIid, options: GlMergeOptions) -> Result<GlMergeRequest, AppError> {
+        let mut builder = projects::merge_requests::MergeMergeRequest::builder();
+        builder.project(project_id.0).merge_request(mr_iid.0);
+
+        if options.when_pipeline_succeeds {
+            builder.merge_when_pipeline_succeeds(true);
+        }
+        if let Some(squash) = options.squash {
+            builder.squash(squash);
+        }
+        if let Some(remove_source_branch) = options.remove_source_branch {
+            builder.should_remove_source_branch(remove_source_branch);
+        }
+
+        let request = builder.build().with_code(ErrorCode::ApiError)?;
+        let mr: GlMergeRequest = request.query(&self.client).api_error()?;
+
+        Ok(mr)
+    }
+
+    pub fn rebase_mr(&self, project_id: ProjectId, mr_iid: MrIid) -> Result<(), AppError> {
+        let request = projects::merge_requests::RebaseMergeRequest::builder()
+            .project(project_id.0)
+            .merge_request(mr_iid.0)
+            .build().with_code(ErrorCode::ApiError)?;
+
+        ignore(request).query(&self.client).api_error()?;
+
+        Ok(())
+    }
+
+    pub fn set_mr_state(&self, project_id: ProjectId, mr_iid: MrIid, close: bool) -> Result<GlMergeRequest, AppError> {
+        let state_event = if close {
+            projects::merge_requests::MergeRequestStateEvent::Close
+        } else {
+            projects::merge_requests::MergeRequestStateEvent::Reopen
+        };
+
+        let request = projects::merge_requests::EditMergeRequest::builder()
+            .project(project_id.0)
+            .merge_request(mr_iid.0)
+            .state_event(state_event)
+            .build().with_code(ErrorCode::ApiError)?;
+
+        let mr: GlMergeRequest = request.query(&self.client).api_error()?;
+
+        Ok(mr)
+    }
+
+    pub fn update_mr_target(&self, project_id: ProjectId, mr_iid: MrIid, target_branch: String) -> Result<GlMergeRequest, AppError> {
+        let request = projects::merge_requests::EditMergeRequest::builder()
+            .project(project_id.0)
+            .merge_request(mr_iid.0)
+            .target_branch(target_branch)
+            .build().with_code(ErrorCode::ApiError)?;
+
+        let mr: GlMergeRequest = request.query(&self.client).api_error()?;
+
+        Ok(mr)
+    }
+
+    pub fn get_mr_discussions(&self, project_id: ProjectId, mr_iid: MrIid) -> Result<Vec<GlDiscussion>, AppError> {
+        let discussions_url = projects::merge_requests::discussions::MergeRequestDiscussions::builder()
+            .project(project_id.0)
+            .merge_request(mr_iid.0)
+            .build().with_code(ErrorCode::ApiError)?;
+
+        let discussions: Vec<GlDiscussion> = paged(discussions_url, Pagination::All).query(&self.client).api_error()?;
+
+        Ok(discussions)
+    }
+
+    pub fn get_mr_diffs(&self, project_id: ProjectId, mr_iid: MrIid) -> Result<Vec<GlDiff>, AppError> {
+        let diffs_url = projects::merge_requests::MergeRequestDiffs::builder()
+            .project(project_id.0)
+            .merge_request(mr_iid.0)
+            .build().with_code(ErrorCode::ApiError)?;
+
+        let diffs: Vec<GlDiff> = paged(diffs_url, Pagination::All).query(&self.client).api_error()?;
+
+        Ok(diffs)
+    }
+
+    pub fn reply_to_discussion(&self, project_id: ProjectId, mr_iid: MrIid, discussion_id: String, body: String) -> Result<GlNote, AppError> {
+        let request = DiscussionNoteApi::builder()
+            .project_id(project_id)
+            .mr_iid(mr_iid)
+            .discussion_id(discussion_id)
+            .body(body)
+            .build().with_code(ErrorCode::ApiError)?;
+
+        let note: GlNote = request.query(&self.client).api_error()?;
+
+        Ok(note)
+    }
+
+    pub fn resolve_discussion(&self, project_id: ProjectId, mr_iid: MrIid, discussion_id: String, resolved: bool) -> Result<GlDiscussion, AppError> {
+        let request = ResolveDiscussionApi::builder()
+            .project_id(project_id)
+            .mr_iid(mr_iid)
+            .discussion_id(discussion_id)
+            .resolved(resolved)
+            .build().with_code(ErrorCode::ApiError)?;
+
+        let discussion: GlDiscussion = request.query(&self.client).api_error()?;
+
+        Ok(discussion)
+    }
+
+    pub fn create_mr_note(&self, project_id: ProjectId, mr_iid: MrIid, body: String) -> Result<GlNote, AppError> {
+        let request = projects::merge_requests::notes::CreateMergeRequestNote::builder()
+            .project(project_id.0)
+            .merge_request(mr_iid.0)
+            .body(body)
+            .build().with_code(ErrorCode::ApiError)?;
+
+        let note: GlNote = request.query(&self.client).api_error()?;
+
+        Ok(note)
+    }
+}
+
+// Time tracking
+impl GitlabManager {
+    /// Списать время на задачу. duration в формате GitLab: 1h30m
+    pub fn add_spent_time(&self, project_id: ProjectId, issue_iid: IssueIid, duration: String) -> Result<GlTimeStats, AppError> {
+        let request = IssueSpentTimeApi::builder()
+            .project_id(project_id)
+            .issue_iid(issue_iid)
+            .duration(duration)
+            .build().with_code(ErrorCode::ApiError)?;
+
+        let stats: GlTimeStats = request.query(&self.client).api_error()?;
+
+        Ok(stats)
+    }
+
+    /// Списания пользователя в проекте. Границы периода в ISO 8601, конец периода не включается
+    pub fn get_timelogs(&self, project_id: ProjectId, username: String, start_date: String, end_date: String) -> Result<Vec<GlTimelog>, AppError> {
+        let mut timelogs = vec![];
+        let mut after = None;
+
+        loop {
+            let query = TimelogsQuery::build_query(TimelogsVariables {
+                project_id: format!("gid://gitlab/Project/{}", project_id),
+                username: username.to_string(),
+                start_date: start_date.to_string(),
+                end_date: end_date.to_string(),
+                after,
+            });
+
+            let data = self.client.graphql::<TimelogsQuery>(&query).with_code(ErrorCode::ApiError)?;
+            timelogs.extend(data.timelogs.nodes);
+
+            if !data.timelogs.page_info.has_next_page {
+                break;
+            }
+            after = data.timelogs.page_info.end_cursor;
+        }
+
+        Ok(timelogs)
+    }
 }
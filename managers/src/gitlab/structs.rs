@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use helpers::{EpicIid, GroupId, IssueIid, MrIid, ProjectId};
 
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -8,77 +9,461 @@ pub struct GlUser {
     pub name: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct GlEpic {
-    pub iid: u16,
+    pub id: u64,
+    pub iid: EpicIid,
     pub title: String,
-    pub parent_iid: Option<u16>,
+    pub parent_iid: Option<EpicIid>,
     pub web_url: String,
+    pub state: String,
     pub labels: Vec<String>,
 }
 
-impl GlEpic {
-    pub fn is_techdebt(&self) -> bool {
-        (self.labels.iter().find(|l| *l == "тип::техдолг").is_some()
-            && self.labels.iter().find(|l| *l == "корневой эпик").is_none())
-            || self.title.to_lowercase().contains("техдолг")
-    }
-
-    pub fn get_branch_name(&self) -> String {
-        format!("feature/{}", self.iid)
-    }
+/// Эпик вместе с дочерними эпиками и задачами
+#[derive(Deserialize, Serialize, Debug)]
+pub struct GlEpicTree {
+    pub epic: GlEpic,
+    pub epics: Vec<GlEpicTree>,
+    pub issues: Vec<GlIssue>,
 }
 
 #[derive(Deserialize, Serialize, Debug)]
 pub struct GlEpicShort {
-    pub iid: u16,
+    pub iid: EpicIid,
+    pub title: String,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct GlMilestone {
+    pub id: u64,
     pub title: String,
 }
 
 #[derive(Deserialize, Serialize, Debug)]
 pub struct GlIssue {
-    pub iid: u64,
+    pub iid: IssueIid,
+    pub project_id: ProjectId,
     pub title: String,
+    pub description: Option<String>,
     pub web_url: String,
+    pub state: String,
+    pub labels: Vec<String>,
+    pub assignees: Vec<GlUser>,
+    pub milestone: Option<GlMilestone>,
     pub epic: Option<GlEpicShort>,
 }
 
+/// Данные для создания задачи
+#[derive(Debug, Default)]
+pub struct GlNewIssue {
+    pub title: String,
+    pub description: String,
+    pub labels: Vec<String>,
+    pub milestone_id: Option<u64>,
+    pub weight: Option<u64>,
+    /// Глобальный id эпика (не iid)
+    pub epic_id: Option<u64>,
+    pub assignee_id: Option<u64>,
+}
+
+/// Изменения задачи. Метки добавляются и удаляются инкрементально
+#[derive(Debug, Default)]
+pub struct GlIssueUpdate {
+    pub add_labels: Vec<String>,
+    pub remove_labels: Vec<String>,
+    pub assignee_ids: Option<Vec<u64>>,
+    pub close: bool,
+}
+
+/// Дополнительные параметры создаваемого MR
+#[derive(Debug, Default, Serialize)]
+pub struct GlMergeRequestOptions {
+    pub draft: bool,
+    pub labels: Vec<String>,
+    pub milestone_id: Option<u64>,
+    pub squash: Option<bool>,
+    pub remove_source_branch: Option<bool>,
+    pub allow_collaboration: Option<bool>,
+    pub target_project_id: Option<ProjectId>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GlIssueState {
+    Opened,
+    Closed,
+    All,
+}
+
+/// Фильтры списка задач проекта
+#[derive(Debug, Default)]
+pub struct GlIssuesFilter {
+    pub assignee_id: Option<u64>,
+    pub milestone: Option<String>,
+    pub labels: Vec<String>,
+    pub state: Option<GlIssueState>,
+    /// Глобальный id эпика (не iid)
+    pub epic_id: Option<u64>,
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 pub struct GlGroup {
-    pub id: u64,
+    pub id: GroupId,
     pub name: String,
     pub web_url: String,
 }
 
+#[derive(Deserialize, Serialize, Debug)]
+pub struct GlProtectedBranch {
+    pub name: String,
+}
+
+impl GlProtectedBranch {
+    /// Имя защищенной ветки может быть шаблоном с `*`
+    pub fn matches(&self, branch: &str) -> bool {
+        let parts: Vec<&str> = self.name.split('*').collect();
+        if parts.len() == 1 {
+            return self.name == branch;
+        }
+
+        let mut rest = match branch.strip_prefix(parts[0]) {
+            Some(rest) => rest,
+            None => return false,
+        };
+
+        for (i, part) in parts.iter().enumerate().skip(1) {
+            if i == parts.len() - 1 {
+                return rest.ends_with(part);
+            }
+            match rest.find(part) {
+                Some(pos) => rest = &rest[pos + part.len()..],
+                None => return false,
+            }
+        }
+
+        true
+    }
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 pub struct GlProject {
-    pub id: u64,
+    pub id: ProjectId,
     pub name: String,
     pub web_url: String,
+    pub http_url_to_repo: String,
+    pub ssh_url_to_repo: String,
 }
 
 
 #[derive(Deserialize, Serialize, Debug)]
 pub struct GlPipeline {
-    id: u64,
-    iid: u64,
-    status: String,
-    web_url: String,
+    pub id: u64,
+    pub iid: u64,
+    pub status: String,
+    pub web_url: String,
 }
 
 
-
 #[derive(Deserialize, Serialize, Debug)]
 pub struct GlMergeRequest {
     pub id: u64,
-    pub iid: u64,
+    pub iid: MrIid,
     pub title: String,
+    pub description: Option<String>,
+    pub state: String,
     pub web_url: String,
+    pub author: GlUser,
     pub assignees: Vec<GlUser>,
     pub reviewers: Vec<GlUser>,
     pub target_branch: String,
     pub source_branch: String,
+    pub source_project_id: ProjectId,
+    pub target_project_id: ProjectId,
+    /// Последний коммит ветки-источника
+    #[serde(default)]
+    pub sha: Option<String>,
+    #[serde(default)]
+    pub draft: bool,
     pub merge_status: String,
+    #[serde(default)]
+    pub detailed_merge_status: Option<String>,
     pub has_conflicts: bool,
     pub head_pipeline: Option<GlPipeline>,
 }
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct GlApprovedBy {
+    pub user: GlUser,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct GlApprovals {
+    #[serde(default)]
+    pub approved: bool,
+    #[serde(default)]
+    pub approvals_required: u64,
+    #[serde(default)]
+    pub approvals_left: u64,
+    #[serde(default)]
+    pub approved_by: Vec<GlApprovedBy>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GlMergeRequestState {
+    Opened,
+    Closed,
+    Merged,
+    All,
+}
+
+/// Фильтры списка MR проекта
+#[derive(Debug, Default)]
+pub struct GlMergeRequestsFilter {
+    pub source_branch: Option<String>,
+    pub target_branch: Option<String>,
+    pub author_id: Option<u64>,
+    pub reviewer_id: Option<u64>,
+    pub state: Option<GlMergeRequestState>,
+}
+
+/// Параметры мержа MR
+#[derive(Debug, Default)]
+pub struct GlMergeOptions {
+    pub when_pipeline_succeeds: bool,
+    pub squash: Option<bool>,
+    pub remove_source_branch: Option<bool>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct GlNotePosition {
+    pub old_path: Option<String>,
+    pub new_path: Option<String>,
+    pub old_line: Option<u64>,
+    pub new_line: Option<u64>,
+}
+
+impl GlNotePosition {
+    pub fn get_path(&self) -> String {
+        self.new_path.to_owned().or(self.old_path.to_owned()).unwrap_or_default()
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct GlNote {
+    pub id: u64,
+    pub body: String,
+    pub author: GlUser,
+    pub created_at: String,
+    #[serde(default)]
+    pub system: bool,
+    #[serde(default)]
+    pub resolvable: bool,
+    #[serde(default)]
+    pub resolved: bool,
+    pub position: Option<GlNotePosition>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct GlDiscussion {
+    pub id: String,
+    #[serde(default)]
+    pub individual_note: bool,
+    pub notes: Vec<GlNote>,
+}
+
+impl GlDiscussion {
+    pub fn is_resolvable(&self) -> bool {
+        self.notes.iter().any(|n| n.resolvable)
+    }
+
+    pub fn is_resolved(&self) -> bool {
+        self.notes.iter().filter(|n| n.resolvable).all(|n| n.resolved)
+    }
+
+    pub fn get_position(&self) -> Option<&GlNotePosition> {
+        self.notes.first().and_then(|n| n.position.as_ref())
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct GlDiff {
+    pub old_path: String,
+    pub new_path: String,
+    pub diff: String,
+}
+
+impl GlDiff {
+    /// Строки хунка вокруг строки нового (или старого) файла
+    pub fn get_context(&self, new_line: Option<u64>, old_line: Option<u64>, radius: u64) -> Vec<String> {
+        let mut context = vec![];
+        let (mut old_number, mut new_number) = (0u64, 0u64);
+
+        for line in self.diff.lines() {
+            if let Some(header) = line.strip_prefix("@@ ") {
+                let (old_start, new_start) = parse_hunk_header(header);
+                old_number = old_start;
+                new_number = new_start;
+                continue;
+            }
+
+            if line.starts_with('\\') {
+                continue;
+            }
+
+            let (old_current, new_current) = match line.chars().next() {
+                Some('+') => { new_number += 1; (None, Some(new_number - 1)) }
+                Some('-') => { old_number += 1; (Some(old_number - 1), None) }
+                _ => { old_number += 1; new_number += 1; (Some(old_number - 1), Some(new_number - 1)) }
+            };
+
+            let is_near = |current: Option<u64>, target: Option<u64>| match (current, target) {
+                (Some(c), Some(t)) => c + radius >= t && c <= t + radius,
+                _ => false,
+            };
+
+            if is_near(new_current, new_line) || (new_line.is_none() && is_near(old_current, old_line)) {
+                context.push(line.to_string());
+            }
+        }
+
+        context
+    }
+}
+
+fn parse_hunk_header(header: &str) -> (u64, u64) {
+    let mut parts = header.split_whitespace();
+    let parse = |part: Option<&str>, prefix: char| {
+        part.and_then(|p| p.strip_prefix(prefix))
+            .and_then(|p| p.split(',').next())
+            .and_then(|p| p.parse::<u64>().ok())
+            .unwrap_or(0)
+    };
+
+    let old_start = parse(parts.next(), '-');
+    let new_start = parse(parts.next(), '+');
+
+    (old_start, new_start)
+}
+
+/// Списание времени из GraphQL API. iid в GraphQL приходят строками
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GlTimelog {
+    pub spent_at: String,
+    /// Секунды
+    pub time_spent: i64,
+    pub issue: Option<GlTimelogIssue>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GlTimelogIssue {
+    pub iid: String,
+    pub title: String,
+    pub web_url: String,
+    pub epic: Option<GlTimelogEpic>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct GlTimelogEpic {
+    pub iid: String,
+    pub title: String,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct GlTimeStats {
+    pub time_estimate: u64,
+    pub total_time_spent: u64,
+    pub human_total_time_spent: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn protected(name: &str) -> GlProtectedBranch {
+        GlProtectedBranch { name: name.to_string() }
+    }
+
+    #[test]
+    fn protected_branch_matches_exact_name() {
+        assert!(protected("master").matches("master"));
+        assert!(!protected("master").matches("master2"));
+        assert!(!protected("master").matches("feature/master"));
+    }
+
+    #[test]
+    fn protected_branch_matches_wildcards() {
+        assert!(protected("release/*").matches("release/1.0"));
+        assert!(protected("release/*").matches("release/"));
+        assert!(!protected("release/*").matches("hotfix/1.0"));
+
+        assert!(protected("*-stable").matches("1.0-stable"));
+        assert!(!protected("*-stable").matches("1.0-stable-old"));
+
+        assert!(protected("*").matches("12-task"));
+    }
+
+    #[test]
+    fn protected_branch_matches_several_wildcards() {
+        assert!(protected("release/*/*-rc").matches("release/1/2-rc"));
+        assert!(!protected("release/*/*-rc").matches("release/1-rc"));
+        assert!(protected("v*.*").matches("v1.2"));
+        assert!(!protected("v*.*").matches("v12"));
+    }
+
+    fn diff(diff: &str) -> GlDiff {
+        GlDiff { old_path: "a.rs".to_string(), new_path: "a.rs".to_string(), diff: diff.to_string() }
+    }
+
+    #[test]
+    fn hunk_header_with_counts() {
+        assert_eq!(parse_hunk_header("-10,7 +12,8 @@ fn main() {"), (10, 12));
+    }
+
+    #[test]
+    fn hunk_header_without_counts() {
+        assert_eq!(parse_hunk_header("-1 +1 @@"), (1, 1));
+    }
+
+    #[test]
+    fn hunk_header_of_new_and_deleted_file() {
+        assert_eq!(parse_hunk_header("-0,0 +1,3 @@"), (0, 1));
+        assert_eq!(parse_hunk_header("-1,3 +0,0 @@"), (1, 0));
+    }
+
+    #[test]
+    fn context_around_new_line() {
+        let diff = diff("@@ -10,4 +10,5 @@\n a\n b\n-c\n+C\n+D\n e\n");
+
+        assert_eq!(diff.get_context(Some(12), None, 1), vec![" b", "+C", "+D"]);
+    }
+
+    #[test]
+    fn context_at_file_start() {
+        let diff = diff("@@ -1 +1 @@\n-old\n+new\n\\ No newline at end of file\n");
+
+        assert_eq!(diff.get_context(Some(1), None, 3), vec!["+new"]);
+    }
+
+    #[test]
+    fn context_in_new_file() {
+        let diff = diff("@@ -0,0 +1,3 @@\n+a\n+b\n+c\n");
+
+        assert_eq!(diff.get_context(Some(1), None, 1), vec!["+a", "+b"]);
+    }
+
+    #[test]
+    fn context_in_deleted_file_uses_old_line() {
+        let diff = diff("@@ -1,3 +0,0 @@\n-a\n-b\n-c\n");
+
+        assert_eq!(diff.get_context(None, Some(3), 1), vec!["-b", "-c"]);
+    }
+
+    #[test]
+    fn context_in_second_hunk() {
+        let diff = diff("@@ -1,2 +1,2 @@\n a\n-b\n+B\n@@ -20,2 +20,3 @@\n x\n+y\n z\n");
+
+        assert_eq!(diff.get_context(Some(21), None, 0), vec!["+y"]);
+    }
+}
@@ -0,0 +1,64 @@
+use helpers::{t, AppError, ErrorCode, GroupId, ProjectId};
+use crate::gitlab::GitlabManager;
+use crate::gitlab::structs::{GlEpic, GlIssue};
+use crate::tracker::{Feature, FeatureKey, IssueTracker, Task, TaskKey};
+
+/// Задачи и эпики GitLab в рамках проекта и его группы
+pub struct GitlabTracker<'a> {
+    manager: &'a GitlabManager,
+    project_id: ProjectId,
+    group_id: GroupId,
+}
+
+impl<'a> GitlabTracker<'a> {
+    pub fn new(manager: &'a GitlabManager, project_id: ProjectId, group_id: GroupId) -> Self {
+        Self { manager, project_id, group_id }
+    }
+}
+
+impl IssueTracker for GitlabTracker<'_> {
+    fn get_task(&self, key: &TaskKey) -> Result<Task, AppError> {
+        match key {
+            TaskKey::Gitlab(iid) => Ok(self.manager.get_issue(*iid, self.project_id)?.into()),
+            TaskKey::Jira(key) => Err(AppError::new(ErrorCode::InvalidTaskKey, t!("gitlab-not-gitlab-task", task = key))),
+        }
+    }
+
+    fn get_feature(&self, key: &FeatureKey) -> Result<Feature, AppError> {
+        match key {
+            FeatureKey::Gitlab(iid) => Ok(self.manager.get_epic(*iid, self.group_id)?.into()),
+            FeatureKey::Jira(key) => Err(AppError::new(ErrorCode::InvalidFeatureKey, t!("gitlab-not-gitlab-epic", epic = key))),
+        }
+    }
+
+    fn get_root_feature(&self, key: &FeatureKey) -> Result<Feature, AppError> {
+        match key {
+            FeatureKey::Gitlab(iid) => Ok(self.manager.get_parent_epic(*iid, self.group_id)?.into()),
+            FeatureKey::Jira(key) => Err(AppError::new(ErrorCode::InvalidFeatureKey, t!("gitlab-not-gitlab-epic", epic = key))),
+        }
+    }
+}
+
+impl From<GlIssue> for Task {
+    fn from(issue: GlIssue) -> Self {
+        Task {
+            key: TaskKey::Gitlab(issue.iid),
+            title: issue.title,
+            description: issue.description,
+            web_url: issue.web_url,
+            labels: issue.labels,
+            feature: issue.epic.map(|epic| FeatureKey::Gitlab(epic.iid)),
+        }
+    }
+}
+
+impl From<GlEpic> for Feature {
+    fn from(epic: GlEpic) -> Self {
+        Feature {
+            key: FeatureKey::Gitlab(epic.iid),
+            title: epic.title,
+            web_url: epic.web_url,
+            labels: epic.labels,
+        }
+    }
+}
@@ -0,0 +1,137 @@
+pub mod structs;
+
+use log::error;
+use reqwest::blocking::Client;
+use helpers::{t, AppError, ErrorCode, JiraConfig, WithCode};
+use crate::jira::structs::JiraIssue;
+use crate::tracker::{Feature, FeatureKey, IssueTracker, Task, TaskKey};
+
+#[derive(Clone)]
+pub struct JiraManager {
+    client: Client,
+    config: JiraConfig,
+}
+
+impl JiraManager {
+    pub fn new(config: JiraConfig) -> Result<Self, AppError> {
+        if config.token.is_empty() {
+            return Err(AppError::new(ErrorCode::Config, t!("jira-token-not-set")));
+        }
+
+        let client = Client::builder().build().with_code(ErrorCode::ApiError)?;
+
+        Ok(Self { client, config })
+    }
+
+    pub fn get_issue(&self, key: &str) -> Result<JiraIssue, AppError> {
+        let mut fields = vec!["summary", "description", "labels", "issuetype", "parent"];
+        if let Some(field) = &self.config.epic_link_field {
+            fields.push(field.as_str());
+        }
+
+        let url = format!("{}/rest/api/2/issue/{}", self.config.host.trim_end_matches('/'), key);
+
+        let mut request = self.client.get(url).query(&[("fields", fields.join(","))]);
+        request = if self.config.user.is_empty() {
+            request.bearer_auth(&self.config.token)
+        } else {
+            request.basic_auth(&self.config.user, Some(&self.config.token))
+        };
+
+        let response = request.send().with_code(ErrorCode::ApiError)?;
+
+        if !response.status().is_success() {
+            error!("{:?}", response);
+            let code = if response.status() == reqwest::StatusCode::NOT_FOUND { ErrorCode::NotFound } else { ErrorCode::ApiError };
+            return Err(AppError::new(code, t!("jira-request-failed", status = response.status(), key = key)));
+        }
+
+        let issue: JiraIssue = response.json().with_code(ErrorCode::ApiError)?;
+
+        Ok(issue)
+    }
+
+    pub fn get_web_url(&self, key: &str) -> String {
+        format!("{}/browse/{}", self.config.host.trim_end_matches('/'), key)
+    }
+
+    /// Эпик задачи: поле Epic Link, родитель-эпик или эпик родителя подзадачи
+    fn get_epic_key(&self, issue: &JiraIssue) -> Result<Option<String>, AppError> {
+        let epic_link = self.config.epic_link_field.as_ref()
+            .and_then(|field| issue.fields.extra.get(field))
+            .and_then(|v| v.as_str());
+
+        if let Some(epic) = epic_link {
+            return Ok(Some(epic.to_string()));
+        }
+
+        match &issue.fields.parent {
+            None => Ok(None),
+            Some(parent) if parent.is_epic() => Ok(Some(parent.key.to_string())),
+            Some(parent) => {
+                let parent_issue = self.get_issue(parent.key.as_str())?;
+                self.get_epic_key(&parent_issue)
+            }
+        }
+    }
+}
+
+impl IssueTracker for JiraManager {
+    fn get_task(&self, key: &TaskKey) -> Result<Task, AppError> {
+        let key = match key {
+            TaskKey::Jira(key) => key,
+            TaskKey::Gitlab(iid) => return Err(AppError::new(ErrorCode::InvalidTaskKey, t!("jira-not-jira-task", task = iid))),
+        };
+
+        let issue = self.get_issue(key)?;
+        let feature = self.get_epic_key(&issue)?.map(FeatureKey::Jira);
+
+        Ok(Task {
+            key: TaskKey::Jira(issue.key.to_string()),
+            title: issue.fields.summary,
+            description: issue.fields.description,
+            web_url: self.get_web_url(issue.key.as_str()),
+            labels: issue.fields.labels,
+            feature,
+        })
+    }
+
+    fn get_feature(&self, key: &FeatureKey) -> Result<Feature, AppError> {
+        let key = match key {
+            FeatureKey::Jira(key) => key,
+            FeatureKey::Gitlab(iid) => return Err(AppError::new(ErrorCode::InvalidFeatureKey, t!("jira-not-jira-epic", epic = iid))),
+        };
+
+        let epic = self.get_issue(key)?;
+
+        Ok(Feature {
+            key: FeatureKey::Jira(epic.key.to_string()),
+            title: epic.fields.summary,
+            web_url: self.get_web_url(epic.key.as_str()),
+            labels: epic.fields.labels,
+        })
+    }
+
+    fn get_root_feature(&self, key: &FeatureKey) -> Result<Feature, AppError> {
+        let key = match key {
+            FeatureKey::Jira(key) => key,
+            FeatureKey::Gitlab(iid) => return Err(AppError::new(ErrorCode::InvalidFeatureKey, t!("jira-not-jira-epic", epic = iid))),
+        };
+
+        let epic = self.get_issue(key)?;
+
+        match epic.fields.parent {
+            Some(parent) => {
+                self.get_root_feature(&FeatureKey::Jira(parent.key))
+            }
+            None => {
+                Ok(Feature {
+                    key: FeatureKey::Jira(epic.key.to_string()),
+                    title: epic.fields.summary,
+                    web_url: self.get_web_url(epic.key.as_str()),
+                    labels: epic.fields.labels,
+                })
+            }
+        }
+    }
+}
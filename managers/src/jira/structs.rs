@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct JiraIssueType {
+    pub name: String,
+    #[serde(default)]
+    pub subtask: bool,
+    #[serde(rename = "hierarchyLevel", default)]
+    pub hierarchy_level: i32,
+}
+
+impl JiraIssueType {
+    pub fn is_epic(&self) -> bool {
+        self.name == "Epic" || self.hierarchy_level >= 1
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct JiraParentFields {
+    pub issuetype: JiraIssueType,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct JiraParent {
+    pub key: String,
+    pub fields: Option<JiraParentFields>,
+}
+
+impl JiraParent {
+    pub fn is_epic(&self) -> bool {
+        self.fields.as_ref().is_some_and(|f| f.issuetype.is_epic())
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct JiraFields {
+    pub summary: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    pub issuetype: JiraIssueType,
+    pub parent: Option<JiraParent>,
+    /// Остальные поля, в том числе кастомное поле Epic Link
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct JiraIssue {
+    pub key: String,
+    pub fields: JiraFields,
+}
@@ -1,5 +1,8 @@
 pub mod git;
 pub mod gitlab;
+pub mod jira;
+pub mod tracker;
 
 pub use git::GitManager;
-pub use gitlab::GitlabManager;
\ No newline at end of file
+pub use gitlab::GitlabManager;
+pub use jira::JiraManager;
@@ -0,0 +1,216 @@
+use std::fmt::{Display, Formatter};
+use serde::{Deserialize, Serialize};
+use helpers::{t, AppError, EpicIid, ErrorCode, IssueIid};
+
+/// Ключ задачи в трекере: iid задачи GitLab или ключ Jira (`PROJ-123`)
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub enum TaskKey {
+    Gitlab(IssueIid),
+    Jira(String),
+}
+
+impl TaskKey {
+    pub fn parse(raw: &str) -> Result<Self, AppError> {
+        if let Ok(iid) = raw.parse::<IssueIid>() {
+            return Ok(TaskKey::Gitlab(iid));
+        }
+
+        if is_jira_key(raw) {
+            return Ok(TaskKey::Jira(raw.to_string()));
+        }
+
+        Err(AppError::new(ErrorCode::InvalidTaskKey, t!("tracker-invalid-task", task = raw)))
+    }
+
+    pub fn from_branch(branch: &str) -> Option<Self> {
+        let raw = branch.strip_suffix("-task")?;
+        Self::parse(raw).ok()
+    }
+
+    pub fn get_branch_name(&self) -> String {
+        format!("{}-task", self)
+    }
+
+    /// Ссылка на задачу в сообщении коммита
+    pub fn get_commit_reference(&self) -> String {
+        match self {
+            TaskKey::Gitlab(iid) => format!("#{}", iid),
+            TaskKey::Jira(key) => key.to_string(),
+        }
+    }
+}
+
+impl Display for TaskKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TaskKey::Gitlab(iid) => write!(f, "{}", iid),
+            TaskKey::Jira(key) => write!(f, "{}", key),
+        }
+    }
+}
+
+/// Ключ фичи в трекере: iid эпика GitLab или ключ эпика Jira
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub enum FeatureKey {
+    Gitlab(EpicIid),
+    Jira(String),
+}
+
+impl FeatureKey {
+    pub fn parse(raw: &str) -> Result<Self, AppError> {
+        if let Ok(iid) = raw.parse::<EpicIid>() {
+            return Ok(FeatureKey::Gitlab(iid));
+        }
+
+        if is_jira_key(raw) {
+            return Ok(FeatureKey::Jira(raw.to_string()));
+        }
+
+        Err(AppError::new(ErrorCode::InvalidFeatureKey, t!("tracker-invalid-feature", feature = raw)))
+    }
+}
+
+impl Display for FeatureKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FeatureKey::Gitlab(iid) => write!(f, "{}", iid),
+            FeatureKey::Jira(key) => write!(f, "{}", key),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Task {
+    pub key: TaskKey,
+    pub title: String,
+    pub description: Option<String>,
+    pub web_url: String,
+    pub labels: Vec<String>,
+    pub feature: Option<FeatureKey>,
+}
+
+impl Task {
+    pub fn get_branch_name(&self) -> String {
+        self.key.get_branch_name()
+    }
+
+    pub fn get_mr_title(&self) -> String {
+        match &self.key {
+            TaskKey::Gitlab(_) => format!("Resolve: {}", self.title),
+            TaskKey::Jira(key) => format!("Resolve: {} {}", key, self.title),
+        }
+    }
+
+    pub fn get_mr_reference(&self) -> String {
+        match &self.key {
+            TaskKey::Gitlab(iid) => format!("#{}", iid),
+            TaskKey::Jira(key) => format!("[{}]({})", key, self.web_url),
+        }
+    }
+
+    /// Ссылка, закрывающая задачу при мерже MR
+    pub fn get_closing_reference(&self) -> String {
+        match &self.key {
+            TaskKey::Gitlab(iid) => format!("Closes #{}", iid),
+            TaskKey::Jira(key) => format!("Closes {}", key),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Feature {
+    pub key: FeatureKey,
+    pub title: String,
+    pub web_url: String,
+    pub labels: Vec<String>,
+}
+
+impl Feature {
+    pub fn is_techdebt(&self) -> bool {
+        (self.labels.iter().any(|l| l == "тип::техдолг")
+            && !self.labels.iter().any(|l| l == "корневой эпик"))
+            || self.title.to_lowercase().contains("техдолг")
+    }
+
+    pub fn get_branch_name(&self) -> String {
+        format!("feature/{}", self.key)
+    }
+}
+
+/// Источник задач и фич, из которого строятся имена веток и MR
+pub trait IssueTracker {
+    fn get_task(&self, key: &TaskKey) -> Result<Task, AppError>;
+
+    fn get_feature(&self, key: &FeatureKey) -> Result<Feature, AppError>;
+
+    /// Корневая фича, от которой ведется ветка `feature/<key>`
+    fn get_root_feature(&self, key: &FeatureKey) -> Result<Feature, AppError>;
+}
+
+fn is_jira_key(raw: &str) -> bool {
+    match raw.split_once('-') {
+        Some((project, number)) => {
+            project.chars().next().is_some_and(|c| c.is_ascii_uppercase())
+                && project.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+                && !number.is_empty()
+                && number.chars().all(|c| c.is_ascii_digit())
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn jira_keys() {
+        assert!(is_jira_key("PROJ-1"));
+        assert!(is_jira_key("P_2-10"));
+        assert!(is_jira_key("AB12-345"));
+
+        assert!(!is_jira_key("proj-1"));
+        assert!(!is_jira_key("PROJ-"));
+        assert!(!is_jira_key("PROJ-1a"));
+        assert!(!is_jira_key("2P-1"));
+        assert!(!is_jira_key("12"));
+        assert!(!is_jira_key("-1"));
+    }
+
+    #[test]
+    fn task_key_parse() {
+        assert_eq!(TaskKey::parse("12"), Ok(TaskKey::Gitlab(IssueIid(12))));
+        assert_eq!(TaskKey::parse("PROJ-1"), Ok(TaskKey::Jira("PROJ-1".to_string())));
+        assert_eq!(TaskKey::parse("P_2-10"), Ok(TaskKey::Jira("P_2-10".to_string())));
+
+        for raw in ["proj-1", "PROJ-", "12-task", ""] {
+            assert_eq!(TaskKey::parse(raw).unwrap_err().code, ErrorCode::InvalidTaskKey, "{}", raw);
+        }
+    }
+
+    #[test]
+    fn task_key_from_branch() {
+        assert_eq!(TaskKey::from_branch("12-task"), Some(TaskKey::Gitlab(IssueIid(12))));
+        assert_eq!(TaskKey::from_branch("PROJ-1-task"), Some(TaskKey::Jira("PROJ-1".to_string())));
+
+        assert_eq!(TaskKey::from_branch("12"), None);
+        assert_eq!(TaskKey::from_branch("proj-1-task"), None);
+        assert_eq!(TaskKey::from_branch("feature/12"), None);
+        assert_eq!(TaskKey::from_branch("-task"), None);
+    }
+
+    #[test]
+    fn task_key_branch_name_round_trip() {
+        for raw in ["12", "PROJ-1"] {
+            let key = TaskKey::parse(raw).unwrap();
+            assert_eq!(TaskKey::from_branch(key.get_branch_name().as_str()), Some(key));
+        }
+    }
+
+    #[test]
+    fn feature_key_parse() {
+        assert_eq!(FeatureKey::parse("7"), Ok(FeatureKey::Gitlab(EpicIid(7))));
+        assert_eq!(FeatureKey::parse("PROJ-7"), Ok(FeatureKey::Jira("PROJ-7".to_string())));
+        assert_eq!(FeatureKey::parse("feature").unwrap_err().code, ErrorCode::InvalidFeatureKey);
+    }
+}